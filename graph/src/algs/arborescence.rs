@@ -0,0 +1,246 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Minimum spanning [arborescence] with the Chu-Liu/Edmonds algorithm.
+//!
+//! An arborescence rooted at `r` is a set of edges such that every vertex other than `r` has
+//! exactly one incoming edge and is reachable from `r` using only these edges.
+//!
+//! [arborescence]: https://en.wikipedia.org/wiki/Edmonds%27_algorithm
+
+use algs::index::vertex_index;
+use prelude::*;
+
+use std::ops::Sub;
+
+const NONE: usize = usize::max_value();
+
+pub trait Arborescence: Incidence {
+    /// Returns the edges of a minimum spanning arborescence rooted at `root` or `None` if some
+    /// vertex is not reachable from `root`. The edges are returned in the order of the vertices
+    /// they enter. Loops and edges entering `root` are never used.
+    ///
+    /// The running time is `O(nm)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate fera_graph;
+    ///
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::Arborescence;
+    /// use fera_graph::sum_prop;
+    ///
+    /// # fn main() {
+    /// let g: StaticDigraph = graph!(
+    ///     4,
+    ///     (0, 1), (0, 2), (1, 2), (2, 1), (2, 3), (3, 1)
+    /// );
+    /// let mut w = g.default_edge_prop(0u32);
+    /// for (e, &x) in g.edges().zip(&[10, 4, 1, 2, 5, 1]) {
+    ///     w[e] = x;
+    /// }
+    /// let v: Vec<_> = g.vertices().collect();
+    /// let tree = g.min_arborescence(v[0], &w).unwrap();
+    /// assert_eq!(3, tree.len());
+    /// assert_eq!(10u32, sum_prop(&w, &tree));
+    ///
+    /// // 0 is not reachable from 1
+    /// assert_eq!(None, g.min_arborescence(v[1], &w));
+    /// # }
+    /// ```
+    fn min_arborescence<W, T>(&self, root: Vertex<Self>, w: W) -> Option<Vec<Edge<Self>>>
+    where
+        Self: IncidenceDigraph,
+        W: EdgePropGet<Self, T>,
+        T: Copy + Ord + Sub<Output = T>,
+    {
+        let index = vertex_index(self, self.vertices());
+        let edges: Vec<_> = self.edges().collect();
+        let arcs = edges
+            .iter()
+            .map(|&e| {
+                let (u, v) = self.ends(e);
+                (index[u], index[v], w.get(e))
+            })
+            .collect();
+        let tree = chu_liu_edmonds(self.num_vertices(), index[root], arcs)?;
+        let mut tree: Vec<_> = tree.into_iter().map(|i| edges[i]).collect();
+        tree.sort_by_key(|&e| index[self.target(e)]);
+        Some(tree)
+    }
+}
+
+impl<G: Incidence> Arborescence for G {}
+
+// A contraction step of the Chu-Liu/Edmonds algorithm.
+struct Level<T> {
+    arcs: Vec<(usize, usize, T)>,
+    // the index of the arc in the previous level for each arc of this level
+    parent: Vec<usize>,
+    // the index of the cheapest arc entering each vertex
+    min_in: Vec<usize>,
+    // the vertices in a contracted cycle
+    in_cycle: Vec<bool>,
+}
+
+// Returns the indices in `arcs` of the arcs of a minimum arborescence rooted at `root`. The
+// vertices of the cycles formed by the cheapest entering arcs are contracted (and the weights of
+// the arcs entering a cycle reduced) until no cycle is left, then the cycles are expanded back
+// choosing all but one of their arcs.
+fn chu_liu_edmonds<T>(n: usize, root: usize, arcs: Vec<(usize, usize, T)>) -> Option<Vec<usize>>
+where
+    T: Copy + Ord + Sub<Output = T>,
+{
+    let mut levels = vec![];
+    let (mut n, mut root, mut arcs) = (n, root, arcs);
+    let mut parent = (0..arcs.len()).collect();
+    loop {
+        let mut min_in = vec![NONE; n];
+        for (i, &(u, v, w)) in arcs.iter().enumerate() {
+            if u != v && v != root && (min_in[v] == NONE || w < arcs[min_in[v]].2) {
+                min_in[v] = i;
+            }
+        }
+        if (0..n).any(|v| v != root && min_in[v] == NONE) {
+            return None;
+        }
+
+        // find the cycles formed by the cheapest entering arcs and assign the new vertices
+        let mut comp = vec![NONE; n];
+        let mut mark = vec![NONE; n];
+        let mut in_cycle = vec![false; n];
+        let mut m = 0;
+        for s in 0..n {
+            let mut v = s;
+            while v != root && mark[v] == NONE {
+                mark[v] = s;
+                v = arcs[min_in[v]].0;
+            }
+            if v != root && mark[v] == s && comp[v] == NONE {
+                // a new cycle that contains v
+                while comp[v] == NONE {
+                    comp[v] = m;
+                    in_cycle[v] = true;
+                    v = arcs[min_in[v]].0;
+                }
+                m += 1;
+            }
+        }
+        let has_cycle = m != 0;
+        for c in &mut comp {
+            if *c == NONE {
+                *c = m;
+                m += 1;
+            }
+        }
+
+        let mut next_arcs = vec![];
+        let mut next_parent = vec![];
+        if has_cycle {
+            for (i, &(u, v, w)) in arcs.iter().enumerate() {
+                if comp[u] != comp[v] {
+                    let w = if in_cycle[v] {
+                        w - arcs[min_in[v]].2
+                    } else {
+                        w
+                    };
+                    next_arcs.push((comp[u], comp[v], w));
+                    next_parent.push(i);
+                }
+            }
+        }
+
+        levels.push(Level {
+            arcs,
+            parent,
+            min_in,
+            in_cycle,
+        });
+
+        if !has_cycle {
+            break;
+        }
+
+        n = m;
+        root = comp[root];
+        arcs = next_arcs;
+        parent = next_parent;
+    }
+
+    // the last level has no cycle, so its cheapest entering arcs form an arborescence
+    let last = levels.last().unwrap();
+    let mut tree: Vec<_> = last.min_in.iter().cloned().filter(|&i| i != NONE).collect();
+    for i in (0..levels.len()).rev() {
+        let level = &levels[i];
+        if i + 1 < levels.len() {
+            // exactly one arc of the tree enters each cycle, the other vertices of the cycle are
+            // entered by the cycle arcs
+            let mut entered = vec![false; level.min_in.len()];
+            for &a in &tree {
+                entered[level.arcs[a].1] = true;
+            }
+            for (v, &a) in level.min_in.iter().enumerate() {
+                if level.in_cycle[v] && !entered[v] {
+                    tree.push(a);
+                }
+            }
+        }
+        for a in &mut tree {
+            *a = level.parent[*a];
+        }
+    }
+    Some(tree)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fera_fun::vec;
+    use fun::sum_prop;
+
+    #[test]
+    fn min_arborescence() {
+        // two nested cycles: 1 -> 2 -> 1 and {1, 2} -> 3 -> {1, 2}
+        let g: StaticDigraph = graph!(
+            5,
+            (0, 1),
+            (0, 3),
+            (1, 2),
+            (2, 1),
+            (2, 3),
+            (3, 1),
+            (4, 0),
+            (3, 4),
+            (0, 4)
+        );
+        let mut w = g.default_edge_prop(0i32);
+        for (e, &x) in g.edges().zip(&[20, 15, 1, 1, 2, 2, -5, 3, 8]) {
+            w[e] = x;
+        }
+        let e = vec(g.edges());
+        let v = vec(g.vertices());
+        let tree = g.min_arborescence(v[0], &w).unwrap();
+        assert_eq!(vec![e[5], e[2], e[1], e[7]], tree);
+        assert_eq!(21, sum_prop(&w, &tree));
+
+        let tree = g.min_arborescence(v[4], &w).unwrap();
+        assert_eq!(vec![e[6], e[5], e[2], e[1]], tree);
+        assert_eq!(13, sum_prop(&w, &tree));
+    }
+
+    #[test]
+    fn unreachable() {
+        let g: StaticDigraph = graph!(4, (0, 1), (2, 3), (3, 2), (1, 1));
+        let w = g.default_edge_prop(1u8);
+        let v = vec(g.vertices());
+        assert_eq!(None, g.min_arborescence(v[0], &w));
+        assert_eq!(None, g.min_arborescence(v[2], &w));
+
+        let g: StaticDigraph = graph!(1, (0, 0));
+        let w = g.default_edge_prop(1u8);
+        assert_eq!(Some(vec![]), g.min_arborescence(0, &w));
+    }
+}
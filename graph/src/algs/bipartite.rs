@@ -0,0 +1,146 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Test if a graph is [bipartite].
+//!
+//! [bipartite]: https://en.wikipedia.org/wiki/Bipartite_graph
+
+use prelude::*;
+use traverse::*;
+
+pub trait Bipartite: Incidence {
+    /// Returns `true` if the graph has a proper two-coloring of its vertices, see [`bipartition`].
+    ///
+    /// [`bipartition`]: #method.bipartition
+    fn is_bipartite(&self) -> bool
+    where
+        Self: Graph,
+    {
+        self.bipartition().is_ok()
+    }
+
+    /// Returns a vertex property with the side of each vertex in a bipartition of the graph, that
+    /// is, a two-coloring of the vertices such that the ends of every edge have different colors.
+    /// If the graph is not bipartite, the edges of an odd cycle are returned as an error.
+    ///
+    /// The bipartition is computed with a breadth first search, so it runs in `O(n + m)` time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate fera_graph;
+    ///
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::{Bipartite, Paths};
+    ///
+    /// # fn main() {
+    /// let g: StaticGraph = graph!(4, (0, 1), (1, 2), (2, 3), (3, 0));
+    /// let v: Vec<_> = g.vertices().collect();
+    /// let side = g.bipartition().unwrap();
+    /// assert!(!side[v[0]] && side[v[1]] && !side[v[2]] && side[v[3]]);
+    ///
+    /// let g: StaticGraph = graph!(4, (0, 1), (1, 2), (2, 3), (3, 1));
+    /// let cycle = g.bipartition().unwrap_err();
+    /// assert_eq!(3, cycle.len());
+    /// assert!(g.is_walk(&cycle));
+    /// # }
+    /// ```
+    fn bipartition(&self) -> Result<DefaultVertexPropMut<Self, bool>, Vec<Edge<Self>>>
+    where
+        Self: Graph,
+    {
+        let mut vis = Bipartition {
+            side: self.default_vertex_prop(false),
+            parent: self.default_vertex_prop(Self::edge_none()),
+            cycle: None,
+        };
+        self.bfs(&mut vis).run();
+        if let Some(cycle) = vis.cycle {
+            Err(cycle)
+        } else {
+            Ok(vis.side)
+        }
+    }
+}
+
+impl<G: Incidence> Bipartite for G {}
+
+pub struct Bipartition<G: Graph> {
+    side: DefaultVertexPropMut<G, bool>,
+    parent: DefaultVertexPropMut<G, OptionEdge<G>>,
+    cycle: Option<Vec<Edge<G>>>,
+}
+
+impl<G: Graph> Bipartition<G> {
+    fn check(&mut self, g: &G, e: Edge<G>) -> Control {
+        let (u, v) = g.ends(e);
+        if self.side[u] != self.side[v] {
+            return Control::Continue;
+        }
+        // u and v are in the same level of the bfs tree, so the tree paths from u and v to their
+        // lowest common ancestor have the same length and, with e, form an odd cycle
+        let (mut a, mut b) = (u, v);
+        let (mut to_u, mut from_v) = (vec![], vec![]);
+        while a != b {
+            let f = self.parent[a].into_option().unwrap();
+            to_u.push(f);
+            a = g.source(f);
+            let f = self.parent[b].into_option().unwrap();
+            from_v.push(g.reverse(f));
+            b = g.source(f);
+        }
+        to_u.reverse();
+        to_u.push(e);
+        to_u.extend(from_v);
+        self.cycle = Some(to_u);
+        Control::Break
+    }
+}
+
+impl<G: Graph> Visitor<G> for Bipartition<G> {
+    fn discover_tree_edge(&mut self, g: &G, e: Edge<G>) -> Control {
+        let (u, v) = g.ends(e);
+        self.side[v] = !self.side[u];
+        self.parent[v] = e.into();
+        Control::Continue
+    }
+
+    fn discover_back_edge(&mut self, g: &G, e: Edge<G>) -> Control {
+        self.check(g, e)
+    }
+
+    fn discover_cross_or_forward_edge(&mut self, g: &G, e: Edge<G>) -> Control {
+        self.check(g, e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use algs::Paths;
+
+    #[test]
+    fn bipartition() {
+        let g: StaticGraph = graph!(6, (0, 1), (1, 2), (2, 3), (3, 0), (4, 5));
+        let side = g.bipartition().unwrap();
+        assert!(g.edges_ends().all(|(u, v)| side[u] != side[v]));
+        assert!(g.is_bipartite());
+    }
+
+    #[test]
+    fn odd_cycle() {
+        let g: StaticGraph = graph!(7, (0, 1), (0, 2), (1, 3), (2, 4), (3, 4), (4, 5), (5, 6));
+        let cycle = g.bipartition().unwrap_err();
+        assert_eq!(5, cycle.len());
+        assert!(g.is_walk(&cycle));
+        assert_eq!(g.source(cycle[0]), g.target(cycle[4]));
+        assert!(!g.is_bipartite());
+
+        let g: StaticGraph = graph!(2, (0, 1), (1, 1));
+        let cycle = g.bipartition().unwrap_err();
+        assert_eq!(1, cycle.len());
+        assert!(g.is_walk(&cycle));
+    }
+}
@@ -6,16 +6,28 @@
 //!
 //! [Borůvka]: https://en.wikipedia.org/wiki/Borůvka's_algorithm
 
+use algs::mst::{AcceptAll, Visitor};
 use params::*;
 use prelude::*;
 use unionfind::{NewUnionFind, UnionFind, WithUnionFind};
 
+use std::collections::HashSet;
+use std::ops::DerefMut;
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
 pub trait Boruvka: WithUnionFind {
+    /// Returns a builder for the Borůvka's algorithm that produces the edges of a minimum spanning
+    /// forest.
+    ///
+    /// See the [`mst`](../mst/index.html) module for the interface shared by all minimum spanning
+    /// tree algorithms.
     fn boruvka<W>(
         &self,
         weight: W,
-    ) -> BoruvkaAlg<&Self, W, Owned<Vec<OptionEdge<Self>>>, NewUnionFind<Self>> {
-        BoruvkaAlg(self, weight, Owned(vec![]), NewUnionFind(self))
+    ) -> BoruvkaAlg<&Self, W, AcceptAll, Owned<Vec<OptionEdge<Self>>>, NewUnionFind<Self>> {
+        BoruvkaAlg(self, weight, AcceptAll, Owned(vec![]), NewUnionFind(self))
     }
 }
 
@@ -23,53 +35,123 @@ impl<G: WithUnionFind> Boruvka for G {}
 
 generic_struct! {
     #[must_use]
-    pub struct BoruvkaAlg(graph, weight, safe, unionfind)
+    pub struct BoruvkaAlg(graph, weight, visitor, safe, unionfind)
 }
 
-impl<'a, G, W, S, U> BoruvkaAlg<&'a G, W, S, U> {
-    // FIXME: return a iterator, like kruskal and prim
+impl<'a, G, W, V, S, U> BoruvkaAlg<&'a G, W, V, S, U> {
+    /// Returns the edges of the minimum spanning forest.
     pub fn run<T>(self) -> Vec<Edge<G>>
     where
         G: WithUnionFind + WithVertexIndexProp,
         W: EdgePropGet<G, T>,
+        V: Visitor<G>,
+        T: PartialOrd,
+        S: ParamDerefMut<Target = Vec<OptionEdge<G>>>,
+        U: ParamDerefMut<Target = UnionFind<G>>,
+    {
+        self.into_iter().collect()
+    }
+
+    /// Like [`run`](#method.run), but the search for the cheapest edge leaving each component is
+    /// done in parallel. The edges are compared by weight and then by index, so the returned
+    /// edges are the same as the ones returned by `run` (assuming the weights are totally
+    /// ordered).
+    ///
+    /// This method is available only if the `rayon` feature is enabled.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate fera_graph;
+    ///
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::Boruvka;
+    ///
+    /// # fn main() {
+    /// let g: StaticGraph = graph!(4, (0, 1), (1, 2), (2, 3), (3, 0), (0, 2));
+    /// let mut w = g.default_edge_prop(0u32);
+    /// for (e, &x) in g.edges().zip(&[3, 1, 2, 1, 2]) {
+    ///     w[e] = x;
+    /// }
+    /// assert_eq!(g.boruvka(&w).run(), g.boruvka(&w).par_run());
+    /// # }
+    /// ```
+    #[cfg(feature = "rayon")]
+    pub fn par_run<T>(self) -> Vec<Edge<G>>
+    where
+        G: WithUnionFind + Incidence + WithVertexIndexProp + WithEdgeIndexProp + Sync,
+        Vertex<G>: Sync,
+        Edge<G>: Sync,
+        VertexIndexProp<G>: Sync,
+        EdgeIndexProp<G>: Sync,
+        W: EdgePropGet<G, T> + Sync,
+        V: Visitor<G>,
         T: PartialOrd,
         S: ParamDerefMut<Target = Vec<OptionEdge<G>>>,
         U: ParamDerefMut<Target = UnionFind<G>>,
     {
-        let BoruvkaAlg(g, w, safe, ds) = self;
+        let BoruvkaAlg(g, w, mut visitor, safe, ds) = self;
         let mut safe = safe.build();
         let mut ds = ds.build();
         let mut edges = vec![];
         let mut changed = true;
         let index = g.vertex_index();
+        let edge_index = g.edge_index();
+        // the edges in the order of their indices, used to return the edges with the same
+        // orientation as run
+        let all_edges: Vec<_> = g.edges().collect();
+        let vertices: Vec<_> = g.vertices().collect();
+        let mut comp = vec![0; g.num_vertices()];
+        let mut rejected = vec![false; g.num_edges()];
+        let less = |a: usize, b: usize| {
+            let (wa, wb) = (w.get(all_edges[a]), w.get(all_edges[b]));
+            wa < wb || (wa == wb && a < b)
+        };
         safe.resize(g.num_vertices(), None.into());
         while changed && ds.num_sets() > 1 {
             changed = false;
-            for (e, u, v) in g.edges_with_ends() {
-                let u_comp = index.get(ds.find_set(u));
-                let v_comp = index.get(ds.find_set(v));
-                if u_comp != v_comp {
-                    let we = w.get(e);
+            for &v in &vertices {
+                comp[index.get(v)] = index.get(ds.find_set(v));
+            }
+            let cheapest: Vec<Option<usize>> = vertices
+                .par_iter()
+                .map(|&u| {
+                    let u_comp = comp[index.get(u)];
+                    let mut best = None;
+                    for e in g.out_edges(u) {
+                        let i = edge_index.get(e);
+                        if comp[index.get(g.target(e))] != u_comp && !rejected[i] {
+                            best = match best {
+                                Some(j) if !less(i, j) => Some(j),
+                                _ => Some(i),
+                            };
+                        }
+                    }
+                    best
+                })
+                .collect();
+            for (&u, &e) in vertices.iter().zip(&cheapest) {
+                if let Some(i) = e {
+                    let u_comp = comp[index.get(u)];
                     safe[u_comp] = match safe[u_comp].into_option() {
-                        None => e.into(),
-                        Some(f) if we < w.get(f) => e.into(),
-                        _ => safe[u_comp],
-                    };
-                    safe[v_comp] = match safe[v_comp].into_option() {
-                        None => e.into(),
-                        Some(f) if we < w.get(f) => e.into(),
-                        _ => safe[v_comp],
+                        Some(f) if !less(i, edge_index.get(f)) => safe[u_comp],
+                        _ => all_edges[i].into(),
                     };
                 }
             }
-            // FIXME: this should not be linear
             for e in safe.iter_mut() {
                 if let Some(ee) = e.into_option() {
                     let (u, v) = g.ends(ee);
-                    if !ds.in_same_set(u, v) {
-                        ds.union(u, v);
-                        edges.push(ee);
+                    let i = edge_index.get(ee);
+                    if !ds.in_same_set(u, v) && !rejected[i] {
                         changed = true;
+                        if visitor.accept(g, ee) {
+                            ds.union(u, v);
+                            edges.push(ee);
+                        } else {
+                            rejected[i] = true;
+                        }
                     }
                     *e = None.into();
                 }
@@ -79,6 +161,122 @@ impl<'a, G, W, S, U> BoruvkaAlg<&'a G, W, S, U> {
     }
 }
 
+impl<'a, G, W, V, S, U> IntoIterator for BoruvkaAlg<&'a G, W, V, S, U>
+where
+    G: WithUnionFind + WithVertexIndexProp,
+    W: PropGet<Edge<G>>,
+    W::Output: PartialOrd,
+    V: Visitor<G>,
+    S: ParamDerefMut<Target = Vec<OptionEdge<G>>>,
+    U: ParamDerefMut<Target = UnionFind<G>>,
+{
+    type Item = Edge<G>;
+    type IntoIter = Iter<'a, G, W, V, S::Output, U::Output>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let BoruvkaAlg(g, w, visitor, safe, ds) = self;
+        let mut safe = safe.build();
+        safe.resize(g.num_vertices(), None.into());
+        Iter {
+            g,
+            w,
+            visitor,
+            pos: safe.len(),
+            safe,
+            ds: ds.build(),
+            rejected: HashSet::new(),
+            changed: true,
+        }
+    }
+}
+
+pub struct Iter<'a, G, W, V, S, U>
+where
+    G: 'a + WithEdge,
+{
+    g: &'a G,
+    w: W,
+    visitor: V,
+    // the cheapest edge leaving each component in the current round
+    safe: S,
+    pos: usize,
+    ds: U,
+    rejected: HashSet<Edge<G>>,
+    changed: bool,
+}
+
+impl<'a, G, W, V, S, U> Iter<'a, G, W, V, S, U>
+where
+    G: 'a + WithUnionFind + WithVertexIndexProp,
+    W: PropGet<Edge<G>>,
+    W::Output: PartialOrd,
+    S: DerefMut<Target = Vec<OptionEdge<G>>>,
+    U: DerefMut<Target = UnionFind<G>>,
+{
+    fn find_safe_edges(&mut self) {
+        let index = self.g.vertex_index();
+        let safe = &mut *self.safe;
+        for (e, u, v) in self.g.edges_with_ends() {
+            let u_comp = index.get(self.ds.find_set(u));
+            let v_comp = index.get(self.ds.find_set(v));
+            if u_comp != v_comp && (self.rejected.is_empty() || !self.rejected.contains(&e)) {
+                let we = self.w.get(e);
+                safe[u_comp] = match safe[u_comp].into_option() {
+                    None => e.into(),
+                    Some(f) if we < self.w.get(f) => e.into(),
+                    _ => safe[u_comp],
+                };
+                safe[v_comp] = match safe[v_comp].into_option() {
+                    None => e.into(),
+                    Some(f) if we < self.w.get(f) => e.into(),
+                    _ => safe[v_comp],
+                };
+            }
+        }
+    }
+}
+
+impl<'a, G, W, V, S, U> Iterator for Iter<'a, G, W, V, S, U>
+where
+    G: 'a + WithUnionFind + WithVertexIndexProp,
+    W: PropGet<Edge<G>>,
+    W::Output: PartialOrd,
+    V: Visitor<G>,
+    S: DerefMut<Target = Vec<OptionEdge<G>>>,
+    U: DerefMut<Target = UnionFind<G>>,
+{
+    type Item = Edge<G>;
+
+    fn next(&mut self) -> Option<Edge<G>> {
+        loop {
+            // FIXME: this should not be linear
+            while self.pos < self.safe.len() {
+                let e = self.safe[self.pos].into_option();
+                self.safe[self.pos] = None.into();
+                self.pos += 1;
+                if let Some(e) = e {
+                    let (u, v) = self.g.ends(e);
+                    if self.ds.in_same_set(u, v) || self.rejected.contains(&e) {
+                        continue;
+                    }
+                    self.changed = true;
+                    if self.visitor.accept(self.g, e) {
+                        self.ds.union(u, v);
+                        return Some(e);
+                    }
+                    self.rejected.insert(e);
+                }
+            }
+            if !self.changed || self.ds.num_sets() <= 1 {
+                return None;
+            }
+            self.changed = false;
+            self.pos = 0;
+            self.find_safe_edges();
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -106,4 +304,51 @@ mod tests {
         assert_eq!(11usize, sum_prop(&weight, &tree));
         assert_eq!(vec![e[0], e[2], e[1], e[4]], tree);
     }
+
+    #[test]
+    fn visitor() {
+        let g: StaticGraph = graph!(6, (0, 1), (1, 2), (0, 2), (3, 4), (4, 5), (3, 5));
+        let mut weight = g.default_edge_prop(0usize);
+        for (e, &w) in g.edges().zip(&[1, 3, 2, 6, 4, 5]) {
+            weight[e] = w;
+        }
+        let e = vec(g.edges());
+        let mut iter = g.boruvka(&weight).into_iter();
+        assert_eq!(Some(e[0]), iter.next());
+        assert_eq!(Some(e[2]), iter.next());
+        let mut rejected = vec![];
+        let tree = g.boruvka(&weight).visitor(|_: &StaticGraph, f| {
+            if f == e[0] || f == e[5] {
+                rejected.push(f);
+                false
+            } else {
+                true
+            }
+        });
+        assert_eq!(vec![e[2], e[4], e[1], e[3]], tree.run());
+        assert_eq!(vec![e[0], e[5]], rejected);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_run() {
+        // many ties
+        let g: StaticGraph = graph!(
+            6,
+            (0, 1),
+            (1, 2),
+            (2, 0),
+            (3, 4),
+            (4, 5),
+            (5, 3),
+            (2, 3),
+            (0, 5),
+            (1, 4)
+        );
+        let weight = g.default_edge_prop(1u8);
+        let e = vec(g.edges());
+        let tree = g.boruvka(&weight).par_run();
+        assert_eq!(vec![e[0], e[1], e[3], e[4], e[6]], tree);
+        assert_eq!(g.boruvka(&weight).run(), tree);
+    }
 }
@@ -0,0 +1,452 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! [Vertex coloring] algorithms, including greedy coloring, [DSatur] and an exact branch and bound
+//! algorithm.
+//!
+//! In all algorithms the colors are numbered from `0` and loops are ignored.
+//!
+//! [Vertex coloring]: https://en.wikipedia.org/wiki/Graph_coloring
+//! [DSatur]: https://en.wikipedia.org/wiki/DSatur
+
+use algs::index::vertex_index;
+use params::IntoOwned;
+use prelude::*;
+
+use std::cmp;
+use std::collections::HashSet;
+
+const NONE: usize = usize::max_value();
+
+pub trait Coloring: Adjacency {
+    /// Returns `true` if the ends of every edge (that is not a loop) have different colors.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate fera_graph;
+    ///
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::Coloring;
+    /// use fera_graph::props::FnProp;
+    ///
+    /// # fn main() {
+    /// let g: StaticGraph = graph!(3, (0, 1), (1, 2));
+    /// assert!(g.is_proper_coloring(FnProp(|v| v as usize % 2)));
+    /// assert!(!g.is_proper_coloring(FnProp(|v| if v == 2 { 1 } else { 0 })));
+    /// # }
+    /// ```
+    fn is_proper_coloring<P>(&self, color: P) -> bool
+    where
+        Self: AdjacencyGraph,
+        P: VertexPropGet<Self, usize>,
+    {
+        self.edges_ends()
+            .all(|(u, v)| u == v || color.get(u) != color.get(v))
+    }
+
+    /// Colors the vertices in the given `order` (which must contain each vertex exactly once)
+    /// with the smallest color that is not used by the neighbors already colored.
+    ///
+    /// Returns the number of colors used and the color of each vertex.
+    ///
+    /// The running time is `O(n + m)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate fera_graph;
+    ///
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::Coloring;
+    ///
+    /// # fn main() {
+    /// // a crown graph, the natural order uses 3 colors
+    /// let g: StaticGraph = graph!(6, (0, 3), (0, 5), (1, 2), (1, 4), (2, 5), (3, 4));
+    /// let (k, color) = g.greedy_coloring(g.vertices());
+    /// assert_eq!(3, k);
+    /// assert!(g.is_proper_coloring(&color));
+    ///
+    /// let (k, _) = g.greedy_coloring(g.smallest_last_order());
+    /// assert_eq!(2, k);
+    /// # }
+    /// ```
+    fn greedy_coloring<I>(&self, order: I) -> (usize, DefaultVertexPropMut<Self, usize>)
+    where
+        Self: AdjacencyGraph,
+        I: IntoIterator,
+        I::Item: IntoOwned<Vertex<Self>>,
+    {
+        let s = Simple::new(self);
+        let order: Vec<_> = order.into_iter().map(|v| s.index[v.into_owned()]).collect();
+        let mut color = vec![NONE; s.adj.len()];
+        let mut k = 0;
+        // used[c] == u if the color c is used by a neighbor of u
+        let mut used = vec![NONE; s.adj.len() + 1];
+        for u in order {
+            for &v in &s.adj[u] {
+                if color[v] != NONE {
+                    used[color[v]] = u;
+                }
+            }
+            let c = (0..).find(|&c| used[c] != u).unwrap();
+            color[u] = c;
+            k = cmp::max(k, c + 1);
+        }
+        (k, s.into_prop(self, &color))
+    }
+
+    /// Returns the vertices sorted in non increasing order of degree.
+    fn largest_first_order(&self) -> Vec<Vertex<Self>>
+    where
+        Self: AdjacencyGraph,
+    {
+        let s = Simple::new(self);
+        let mut order: Vec<_> = (0..s.adj.len()).collect();
+        order.sort_by_key(|&u| cmp::Reverse(s.adj[u].len()));
+        order.into_iter().map(|u| s.vertices[u]).collect()
+    }
+
+    /// Returns the vertices in smallest last (degeneracy) order, that is, the last vertex has
+    /// minimum degree, the previous one has minimum degree in the graph without the last vertex,
+    /// and so on. In this order each vertex has at most `d` neighbors before it, where `d` is the
+    /// degeneracy of the graph, so the greedy coloring uses at most `d + 1` colors.
+    ///
+    /// The running time is `O(n + m)`.
+    fn smallest_last_order(&self) -> Vec<Vertex<Self>>
+    where
+        Self: AdjacencyGraph,
+    {
+        let s = Simple::new(self);
+        s.smallest_last_order()
+            .into_iter()
+            .map(|u| s.vertices[u])
+            .collect()
+    }
+
+    /// Colors the vertices with the DSatur heuristic, that is, at each step the vertex with the
+    /// largest number of distinct colors in its neighborhood (with ties broken by the largest
+    /// degree) is colored with the smallest available color.
+    ///
+    /// Returns the number of colors used and the color of each vertex.
+    ///
+    /// The running time is `O(n^2 + m)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate fera_graph;
+    ///
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::Coloring;
+    ///
+    /// # fn main() {
+    /// let g: StaticGraph = graph!(6, (0, 3), (0, 5), (1, 2), (1, 4), (2, 5), (3, 4));
+    /// let (k, color) = g.dsatur();
+    /// assert_eq!(2, k);
+    /// assert!(g.is_proper_coloring(&color));
+    /// # }
+    /// ```
+    fn dsatur(&self) -> (usize, DefaultVertexPropMut<Self, usize>)
+    where
+        Self: AdjacencyGraph,
+    {
+        let s = Simple::new(self);
+        let (k, color) = s.dsatur();
+        (k, s.into_prop(self, &color))
+    }
+
+    /// Finds a coloring with the minimum number of colors using a branch and bound algorithm
+    /// based on DSatur. The running time is exponential, so it should only be used with small
+    /// graphs.
+    ///
+    /// Returns the chromatic number and the color of each vertex.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate fera_graph;
+    ///
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::Coloring;
+    ///
+    /// # fn main() {
+    /// // the petersen graph
+    /// let g: StaticGraph = graph!(
+    ///     10,
+    ///     (0, 1), (1, 2), (2, 3), (3, 4), (4, 0),
+    ///     (0, 5), (1, 6), (2, 7), (3, 8), (4, 9),
+    ///     (5, 7), (7, 9), (9, 6), (6, 8), (8, 5)
+    /// );
+    /// let (k, color) = g.minimum_coloring();
+    /// assert_eq!(3, k);
+    /// assert!(g.is_proper_coloring(&color));
+    /// # }
+    /// ```
+    fn minimum_coloring(&self) -> (usize, DefaultVertexPropMut<Self, usize>)
+    where
+        Self: AdjacencyGraph,
+    {
+        let s = Simple::new(self);
+        let (k, color) = s.minimum_coloring();
+        (k, s.into_prop(self, &color))
+    }
+
+    /// Returns the chromatic number of the graph, that is, the minimum number of colors in a
+    /// proper coloring. See [`minimum_coloring`](#method.minimum_coloring).
+    fn chromatic_number(&self) -> usize
+    where
+        Self: AdjacencyGraph,
+    {
+        Simple::new(self).minimum_coloring().0
+    }
+}
+
+impl<G: Adjacency> Coloring for G {}
+
+// A simple graph (without loops and parallel edges) where the vertices are numbered from 0 to n -
+// 1.
+struct Simple<G: AdjacencyGraph> {
+    vertices: Vec<Vertex<G>>,
+    index: DefaultVertexPropMut<G, usize>,
+    adj: Vec<Vec<usize>>,
+}
+
+impl<G: AdjacencyGraph> Simple<G> {
+    fn new(g: &G) -> Self {
+        let vertices: Vec<_> = g.vertices().collect();
+        let index = vertex_index(g, &vertices);
+        // last[v] == u if v was already added as a neighbor of u
+        let mut last = vec![NONE; vertices.len()];
+        let adj = vertices
+            .iter()
+            .enumerate()
+            .map(|(i, &u)| {
+                let mut adj = vec![];
+                for v in g.out_neighbors(u) {
+                    let j = index[v];
+                    if j != i && last[j] != i {
+                        last[j] = i;
+                        adj.push(j);
+                    }
+                }
+                adj
+            })
+            .collect();
+        Simple {
+            vertices,
+            index,
+            adj,
+        }
+    }
+
+    fn into_prop(self, g: &G, color: &[usize]) -> DefaultVertexPropMut<G, usize> {
+        let mut prop = g.default_vertex_prop(0usize);
+        for (&v, &c) in self.vertices.iter().zip(color) {
+            prop[v] = c;
+        }
+        prop
+    }
+
+    fn smallest_last_order(&self) -> Vec<usize> {
+        let n = self.adj.len();
+        let mut degree: Vec<_> = self.adj.iter().map(Vec::len).collect();
+        let mut removed = vec![false; n];
+        // bucket[d] has the vertices with degree d (and possibly some outdated entries)
+        let mut bucket = vec![vec![]; n];
+        for u in 0..n {
+            bucket[degree[u]].push(u);
+        }
+        let mut order = Vec::with_capacity(n);
+        let mut d = 0;
+        while order.len() < n {
+            let u = match bucket[d].pop() {
+                Some(u) => u,
+                None => {
+                    d += 1;
+                    continue;
+                }
+            };
+            if removed[u] || degree[u] != d {
+                continue;
+            }
+            removed[u] = true;
+            order.push(u);
+            for &v in &self.adj[u] {
+                if !removed[v] {
+                    degree[v] -= 1;
+                    bucket[degree[v]].push(v);
+                }
+            }
+            d = d.saturating_sub(1);
+        }
+        order.reverse();
+        order
+    }
+
+    fn dsatur(&self) -> (usize, Vec<usize>) {
+        let n = self.adj.len();
+        let mut color = vec![NONE; n];
+        let mut sat: Vec<HashSet<usize>> = vec![HashSet::new(); n];
+        let mut k = 0;
+        for _ in 0..n {
+            let u = (0..n)
+                .filter(|&u| color[u] == NONE)
+                .max_by_key(|&u| (sat[u].len(), self.adj[u].len(), cmp::Reverse(u)))
+                .unwrap();
+            let c = (0..).find(|c| !sat[u].contains(c)).unwrap();
+            color[u] = c;
+            k = cmp::max(k, c + 1);
+            for &v in &self.adj[u] {
+                sat[v].insert(c);
+            }
+        }
+        (k, color)
+    }
+
+    fn minimum_coloring(&self) -> (usize, Vec<usize>) {
+        let (k, color) = self.dsatur();
+        let mut bb = BranchAndBound {
+            adj: &self.adj,
+            lower: self.clique_size(),
+            best: k,
+            best_color: color,
+            color: vec![NONE; self.adj.len()],
+        };
+        bb.search(0, 0);
+        (bb.best, bb.best_color)
+    }
+
+    // Returns the size of a clique found greedily, which is a lower bound for the chromatic number.
+    fn clique_size(&self) -> usize {
+        let mut clique: Vec<usize> = vec![];
+        // adjacent[v] == u if v is a neighbor of u
+        let mut adjacent = vec![NONE; self.adj.len()];
+        for u in self.smallest_last_order() {
+            for &v in &self.adj[u] {
+                adjacent[v] = u;
+            }
+            if clique.iter().all(|&v| adjacent[v] == u) {
+                clique.push(u);
+            }
+        }
+        clique.len()
+    }
+}
+
+struct BranchAndBound<'a> {
+    adj: &'a [Vec<usize>],
+    lower: usize,
+    best: usize,
+    best_color: Vec<usize>,
+    color: Vec<usize>,
+}
+
+impl<'a> BranchAndBound<'a> {
+    // Extends the current partial coloring, which has num_colored vertices and uses k colors.
+    fn search(&mut self, num_colored: usize, k: usize) {
+        if self.best == self.lower {
+            return;
+        }
+        if num_colored == self.adj.len() {
+            // k < self.best, otherwise the branch would have been pruned
+            self.best = k;
+            self.best_color = self.color.clone();
+            return;
+        }
+        let u = self.select();
+        for c in 0..cmp::min(k + 1, self.best - 1) {
+            if self.adj[u].iter().all(|&v| self.color[v] != c) {
+                self.color[u] = c;
+                self.search(num_colored + 1, cmp::max(k, c + 1));
+                self.color[u] = NONE;
+            }
+        }
+    }
+
+    // Selects the uncolored vertex with maximum saturation.
+    fn select(&self) -> usize {
+        let mut best = (0, 0, NONE);
+        let mut seen = HashSet::new();
+        for u in 0..self.adj.len() {
+            if self.color[u] != NONE {
+                continue;
+            }
+            seen.clear();
+            seen.extend(
+                self.adj[u]
+                    .iter()
+                    .map(|&v| self.color[v])
+                    .filter(|&c| c != NONE),
+            );
+            let key = (seen.len(), self.adj[u].len(), u);
+            if best.2 == NONE || (key.0, key.1) > (best.0, best.1) {
+                best = key;
+            }
+        }
+        best.2
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn smallest_last_order() {
+        // a star with an extra edge between two leafs
+        let g: StaticGraph = graph!(5, (0, 1), (0, 2), (0, 3), (0, 4), (1, 2));
+        let order = g.smallest_last_order();
+        assert_eq!(5, order.len());
+        // 3 and 4 have degree 1, so they are removed first and are the last ones
+        assert!(order[3..].contains(&3) && order[3..].contains(&4));
+        let mut sorted = order.clone();
+        sorted.sort();
+        assert_eq!(vec![0, 1, 2, 3, 4], sorted);
+        assert_eq!(3, g.greedy_coloring(&order).0);
+        assert_eq!(0, g.largest_first_order()[0]);
+    }
+
+    #[test]
+    fn parallel_edges_and_loops() {
+        // a triangle with a pendant vertex, parallel edges and a loop
+        let g: StaticGraph = graph!(4, (0, 1), (1, 0), (0, 0), (0, 2), (1, 2), (2, 3));
+        let order = g.smallest_last_order();
+        assert_eq!(3, order[3]);
+        assert_eq!(3, g.greedy_coloring(&order).0);
+        assert_eq!(3, g.dsatur().0);
+        assert_eq!(3, g.chromatic_number());
+    }
+
+    #[test]
+    fn minimum_coloring() {
+        // a wheel with 5 spokes needs 4 colors
+        let g: StaticGraph = graph!(
+            6,
+            (0, 1),
+            (1, 2),
+            (2, 3),
+            (3, 4),
+            (4, 0),
+            (5, 0),
+            (5, 1),
+            (5, 2),
+            (5, 3),
+            (5, 4)
+        );
+        let (k, color) = g.minimum_coloring();
+        assert_eq!(4, k);
+        assert!(g.is_proper_coloring(&color));
+        assert_eq!(4, g.chromatic_number());
+
+        let g: StaticGraph = graph!(3);
+        assert_eq!(1, g.chromatic_number());
+        let g: StaticGraph = graph!(0);
+        assert_eq!(0, g.chromatic_number());
+    }
+}
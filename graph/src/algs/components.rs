@@ -2,7 +2,10 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
-//! Components related algorithms, including connectivity, cuts, etc.
+//! Components related algorithms, including connectivity, strongly connected components
+//! ([Tarjan]), cuts, etc.
+//!
+//! [Tarjan]: https://en.wikipedia.org/wiki/Tarjan's_strongly_connected_components_algorithm
 
 use prelude::*;
 use props::Color;
@@ -43,6 +46,54 @@ pub trait Components: Incidence {
         con
     }
 
+    /// Computes the strongly connected components of a digraph using the Tarjan's algorithm.
+    ///
+    /// The components are numbered in reverse topological order of the condensation, that is, if
+    /// there is an edge from a vertex in component `a` to a vertex in component `b != a`, then
+    /// `a > b`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate fera_graph;
+    ///
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::Components;
+    ///
+    /// # fn main() {
+    /// let g: StaticDigraph = graph!(5, (0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 3));
+    /// let v: Vec<_> = g.vertices().collect();
+    /// let scc = g.strongly_connected_components();
+    /// assert_eq!(2, scc.num_components());
+    /// assert!(scc.is_connected(v[0], v[2]));
+    /// assert!(scc.is_disconnected(v[2], v[3]));
+    ///
+    /// let dag = scc.condensation(&g);
+    /// assert_eq!(2, dag.num_vertices());
+    /// assert_eq!(1, dag.num_edges());
+    /// # }
+    /// ```
+    fn strongly_connected_components(
+        &self,
+    ) -> StronglyConnectedComponents<Self, DefaultVertexPropMut<Self, usize>>
+    where
+        Self: VertexList + WithVertexProp<Color> + WithVertexProp<usize> + WithVertexProp<bool>,
+    {
+        let mut scc = StronglyConnectedComponents(self, self.vertex_prop(0));
+        self.dfs(&mut scc).run();
+        scc
+    }
+
+    /// Returns `true` if there is a path from every vertex to every other vertex, `false`
+    /// otherwise.
+    fn is_strongly_connected(&self) -> bool
+    where
+        Self: VertexList + WithVertexProp<Color> + WithVertexProp<usize> + WithVertexProp<bool>,
+    {
+        self.strongly_connected_components().num_components() <= 1
+    }
+
     fn cut_vertices(&self) -> Vec<Vertex<Self>>
     where
         Self: Graph,
@@ -51,9 +102,7 @@ pub trait Components: Incidence {
             return vec![];
         }
         let mut vis = FindCutVertices {
-            time: 0,
-            discover: self.vertex_prop(0),
-            low: self.vertex_prop(0),
+            low: LowLink::new(self),
             root: first(self.vertices()),
             root_childs: 0,
             is_cut: self.vertex_prop(false),
@@ -67,14 +116,94 @@ pub trait Components: Incidence {
         Self: Graph,
     {
         let mut vis = FindCutEdges {
-            time: 0,
-            discover: self.vertex_prop(0),
-            low: self.vertex_prop(0),
+            low: LowLink::new(self),
             cuts: vec![],
         };
         self.dfs(&mut vis).run();
         vis.cuts
     }
+
+    /// Computes the biconnected components (blocks) of the graph, that is, the maximal
+    /// subgraphs without cut vertices. Each edge belongs to exactly one block.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate fera_graph;
+    ///
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::Components;
+    ///
+    /// # fn main() {
+    /// // 1 --- 0 --- 3
+    /// //  \   /      |
+    /// //    2        4
+    /// let g: StaticGraph = graph!(5, (0, 1), (0, 2), (0, 3), (1, 2), (3, 4));
+    /// let e: Vec<_> = g.edges().collect();
+    /// let bcc = g.biconnected_components();
+    /// assert_eq!(3, bcc.num_components());
+    /// assert_eq!(bcc.component(e[0]), bcc.component(e[3]));
+    /// assert!(bcc.component(e[2]) != bcc.component(e[4]));
+    ///
+    /// // the blocks {0, 1, 2}, {0, 3} and {3, 4} and the cut vertices 0 and 3
+    /// let (tree, cuts) = bcc.block_cut_tree(&g);
+    /// assert_eq!(5, tree.num_vertices());
+    /// assert_eq!(4, tree.num_edges());
+    /// assert_eq!(vec![0, 3], cuts);
+    /// # }
+    /// ```
+    fn biconnected_components(&self) -> BiconnectedComponents<Self>
+    where
+        Self: Graph,
+    {
+        let mut vis = BiconnectedComponents {
+            low: LowLink::new(self),
+            stack: vec![],
+            comp: self.edge_prop(usize::max_value()),
+            cur: 0,
+        };
+        self.dfs(&mut vis).run();
+        vis
+    }
+
+    /// Computes the 2-edge-connected components of the graph, that is, the components that
+    /// remain after all cut edges (bridges) are removed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate fera_graph;
+    ///
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::Components;
+    ///
+    /// # fn main() {
+    /// // 1 --- 0 --- 3
+    /// //  \   /      |
+    /// //    2        4
+    /// let g: StaticGraph = graph!(5, (0, 1), (0, 2), (0, 3), (1, 2), (3, 4));
+    /// let v: Vec<_> = g.vertices().collect();
+    /// let comps = g.two_edge_connected_components();
+    /// assert_eq!(3, comps.num_components());
+    /// assert!(comps.is_connected(v[1], v[2]));
+    /// assert!(comps.is_disconnected(v[0], v[3]));
+    /// # }
+    /// ```
+    fn two_edge_connected_components(&self) -> TwoEdgeConnectedComponents<Self>
+    where
+        Self: Graph,
+    {
+        let mut vis = TwoEdgeConnectedComponents {
+            low: LowLink::new(self),
+            stack: vec![],
+            comp: self.vertex_prop(0),
+            cur: 0,
+        };
+        self.dfs(&mut vis).run();
+        vis
+    }
 }
 
 impl<G: Incidence> Components for G {}
@@ -173,10 +302,177 @@ where
     }
 }
 
-pub struct FindCutVertices<G: Graph> {
+pub struct StronglyConnectedComponents<G, V>
+where
+    G: WithVertex + WithVertexProp<usize> + WithVertexProp<bool>,
+{
+    comp: V,
+    cur: usize,
+    time: usize,
+    discover: DefaultVertexPropMut<G, usize>,
+    low: DefaultVertexPropMut<G, usize>,
+    stack: Vec<Vertex<G>>,
+    on_stack: DefaultVertexPropMut<G, bool>,
+}
+
+#[allow(non_snake_case)]
+pub fn StronglyConnectedComponents<G, V>(g: &G, comp: V) -> StronglyConnectedComponents<G, V>
+where
+    G: WithVertex + WithVertexProp<usize> + WithVertexProp<bool>,
+{
+    StronglyConnectedComponents {
+        comp,
+        cur: 0,
+        time: 0,
+        discover: g.vertex_prop(0),
+        low: g.vertex_prop(0),
+        stack: vec![],
+        on_stack: g.vertex_prop(false),
+    }
+}
+
+impl<G, V> Visitor<G> for StronglyConnectedComponents<G, V>
+where
+    G: WithEdge + WithVertexProp<usize> + WithVertexProp<bool>,
+    V: VertexPropMut<G, usize>,
+{
+    fn discover_vertex(&mut self, _g: &G, v: Vertex<G>) -> Control {
+        self.discover[v] = self.time;
+        self.low[v] = self.time;
+        self.time += 1;
+        self.stack.push(v);
+        self.on_stack[v] = true;
+        Control::Continue
+    }
+
+    fn discover_back_edge(&mut self, g: &G, e: Edge<G>) -> Control {
+        let (u, v) = g.ends(e);
+        self.low[u] = min(self.low[u], self.discover[v]);
+        Control::Continue
+    }
+
+    fn discover_cross_or_forward_edge(&mut self, g: &G, e: Edge<G>) -> Control {
+        let (u, v) = g.ends(e);
+        if self.on_stack[v] {
+            // v is in a component that is not finished yet, so it is in the same component as u
+            self.low[u] = min(self.low[u], self.discover[v]);
+        }
+        Control::Continue
+    }
+
+    fn finish_tree_edge(&mut self, g: &G, e: Edge<G>) -> Control {
+        let (u, v) = g.ends(e);
+        self.low[u] = min(self.low[u], self.low[v]);
+        Control::Continue
+    }
+
+    fn finish_vertex(&mut self, _g: &G, v: Vertex<G>) -> Control {
+        if self.low[v] == self.discover[v] {
+            // v is the first discovered vertex of its component
+            loop {
+                let u = self.stack.pop().unwrap();
+                self.on_stack[u] = false;
+                self.comp[u] = self.cur;
+                if u == v {
+                    break;
+                }
+            }
+            self.cur += 1;
+        }
+        Control::Continue
+    }
+}
+
+impl<G, V> StronglyConnectedComponents<G, V>
+where
+    G: WithEdge + WithVertexProp<usize> + WithVertexProp<bool>,
+    V: VertexPropMut<G, usize>,
+{
+    pub fn is_connected(&self, u: Vertex<G>, v: Vertex<G>) -> bool {
+        self.comp[u] == self.comp[v]
+    }
+
+    pub fn is_disconnected(&self, u: Vertex<G>, v: Vertex<G>) -> bool {
+        self.comp[u] != self.comp[v]
+    }
+
+    pub fn component(&self, v: Vertex<G>) -> usize {
+        self.comp[v]
+    }
+
+    pub fn num_components(&self) -> usize {
+        self.cur
+    }
+
+    /// Returns the vertex property that maps each vertex to its component.
+    pub fn into_components(self) -> V {
+        self.comp
+    }
+
+    /// Creates the condensation of `g`, that is, a DAG with one vertex for each component and
+    /// an edge `(a, b)` if there is an edge from a vertex in component `a` to a vertex in
+    /// component `b != a`.
+    pub fn condensation(&self, g: &G) -> StaticDigraph
+    where
+        G: EdgeList,
+    {
+        let mut edges = vec(g
+            .edges_ends()
+            .map(|(u, v)| (self.comp[u], self.comp[v]))
+            .filter(|&(a, b)| a != b));
+        edges.sort();
+        edges.dedup();
+        StaticDigraph::new_with_edges(self.cur, edges)
+    }
+}
+
+// The discovery time and the low-link of each vertex in a depth-first search of an undirected
+// graph, that is, the smallest discovery time reachable from the subtree of the vertex using at
+// most one back edge. The search does not report the tree edge to the parent as a back edge.
+struct LowLink<G: Graph> {
     time: u64,
     discover: DefaultVertexPropMut<G, u64>,
     low: DefaultVertexPropMut<G, u64>,
+}
+
+impl<G: Graph> LowLink<G> {
+    fn new(g: &G) -> Self {
+        LowLink {
+            time: 0,
+            discover: g.vertex_prop(0),
+            low: g.vertex_prop(0),
+        }
+    }
+
+    fn discover_vertex(&mut self, v: Vertex<G>) {
+        self.discover[v] = self.time;
+        self.low[v] = self.time;
+        self.time += 1;
+    }
+
+    // Updates the low-link of u with the back edge (u, v).
+    fn back_edge(&mut self, u: Vertex<G>, v: Vertex<G>) {
+        self.low[u] = min(self.low[u], self.discover[v]);
+    }
+
+    // Updates the low-link of u with the low-link of its child v.
+    fn finish_tree_edge(&mut self, u: Vertex<G>, v: Vertex<G>) {
+        self.low[u] = min(self.low[u], self.low[v]);
+    }
+
+    // Returns true if removing u disconnects the subtree of its child v from the rest.
+    fn separates(&self, u: Vertex<G>, v: Vertex<G>) -> bool {
+        self.low[v] >= self.discover[u]
+    }
+
+    // Returns true if the tree edge (u, v) is a cut edge.
+    fn is_cut_edge(&self, u: Vertex<G>, v: Vertex<G>) -> bool {
+        self.low[v] > self.discover[u]
+    }
+}
+
+pub struct FindCutVertices<G: Graph> {
+    low: LowLink<G>,
     root: Vertex<G>,
     root_childs: u64,
     is_cut: DefaultVertexPropMut<G, bool>,
@@ -197,9 +493,7 @@ impl<G: Graph> Visitor<G> for FindCutVertices<G> {
     }
 
     fn discover_vertex(&mut self, _g: &G, v: Vertex<G>) -> Control {
-        self.discover[v] = self.time;
-        self.low[v] = self.time;
-        self.time += 1;
+        self.low.discover_vertex(v);
         Control::Continue
     }
 
@@ -212,17 +506,14 @@ impl<G: Graph> Visitor<G> for FindCutVertices<G> {
 
     fn discover_back_edge(&mut self, g: &G, e: Edge<G>) -> Control {
         let (u, v) = g.ends(e);
-        if self.discover[v] != self.discover[u] + 1 {
-            // v is not the dfs parent of u
-            self.low[u] = min(self.low[u], self.discover[v]);
-        }
+        self.low.back_edge(u, v);
         Control::Continue
     }
 
     fn finish_tree_edge(&mut self, g: &G, e: Edge<G>) -> Control {
         let (u, v) = g.ends(e);
-        self.low[u] = min(self.low[u], self.low[v]);
-        if self.root != u && self.low[v] >= self.discover[u] {
+        self.low.finish_tree_edge(u, v);
+        if self.root != u && self.low.separates(u, v) {
             self.is_cut[u] = true;
         }
         Control::Continue
@@ -230,39 +521,213 @@ impl<G: Graph> Visitor<G> for FindCutVertices<G> {
 }
 
 pub struct FindCutEdges<G: Graph> {
-    time: u64,
-    discover: DefaultVertexPropMut<G, u64>,
-    low: DefaultVertexPropMut<G, u64>,
+    low: LowLink<G>,
     cuts: Vec<Edge<G>>,
 }
 
 impl<G: Graph> Visitor<G> for FindCutEdges<G> {
     fn discover_vertex(&mut self, _g: &G, v: Vertex<G>) -> Control {
-        self.discover[v] = self.time;
-        self.low[v] = self.time;
-        self.time += 1;
+        self.low.discover_vertex(v);
         Control::Continue
     }
 
     fn discover_back_edge(&mut self, g: &G, e: Edge<G>) -> Control {
-        let (v, parent) = g.ends(e);
-        if self.discover[parent] != self.discover[v] + 1 {
-            // this is not an edge to parent
-            self.low[v] = min(self.low[v], self.discover[parent]);
-        }
+        let (u, v) = g.ends(e);
+        self.low.back_edge(u, v);
         Control::Continue
     }
 
     fn finish_tree_edge(&mut self, g: &G, e: Edge<G>) -> Control {
         let (u, v) = g.ends(e);
-        self.low[u] = min(self.low[u], self.low[v]);
-        if self.low[v] > self.discover[u] {
+        self.low.finish_tree_edge(u, v);
+        if self.low.is_cut_edge(u, v) {
             self.cuts.push(e)
         }
         Control::Continue
     }
 }
 
+/// The biconnected components (blocks) of a graph, created by
+/// [`Components::biconnected_components`].
+///
+/// [`Components::biconnected_components`]: trait.Components.html#method.biconnected_components
+pub struct BiconnectedComponents<G: Graph> {
+    low: LowLink<G>,
+    stack: Vec<Edge<G>>,
+    comp: DefaultEdgePropMut<G, usize>,
+    cur: usize,
+}
+
+impl<G: Graph> Visitor<G> for BiconnectedComponents<G> {
+    fn discover_vertex(&mut self, _g: &G, v: Vertex<G>) -> Control {
+        self.low.discover_vertex(v);
+        Control::Continue
+    }
+
+    fn discover_tree_edge(&mut self, _g: &G, e: Edge<G>) -> Control {
+        self.stack.push(e);
+        Control::Continue
+    }
+
+    fn discover_back_edge(&mut self, g: &G, e: Edge<G>) -> Control {
+        let (u, v) = g.ends(e);
+        if u == v {
+            // a loop is a block by itself, it is reported once for each of its two incidences
+            if self.comp[e] == usize::max_value() {
+                self.comp[e] = self.cur;
+                self.cur += 1;
+            }
+        } else {
+            self.stack.push(e);
+            self.low.back_edge(u, v);
+        }
+        Control::Continue
+    }
+
+    fn finish_tree_edge(&mut self, g: &G, e: Edge<G>) -> Control {
+        let (u, v) = g.ends(e);
+        self.low.finish_tree_edge(u, v);
+        if self.low.separates(u, v) {
+            // u separates the subtree of v, so the edges above e in the stack form a block
+            loop {
+                let f = self.stack.pop().unwrap();
+                self.comp[f] = self.cur;
+                if f == e {
+                    break;
+                }
+            }
+            self.cur += 1;
+        }
+        Control::Continue
+    }
+}
+
+impl<G: Graph> BiconnectedComponents<G> {
+    /// Returns the block of the edge `e`, a number in `0..num_components()`.
+    pub fn component(&self, e: Edge<G>) -> usize {
+        self.comp[e]
+    }
+
+    /// Returns the number of blocks.
+    pub fn num_components(&self) -> usize {
+        self.cur
+    }
+
+    /// Returns the edge property that maps each edge to its block.
+    pub fn into_components(self) -> DefaultEdgePropMut<G, usize> {
+        self.comp
+    }
+
+    /// Creates the block-cut tree of `g`, that is, a forest with one vertex for each block and
+    /// one vertex for each cut vertex of `g`, and an edge between a block and each of its cut
+    /// vertices.
+    ///
+    /// The first `num_components()` vertices of the tree corresponds to the blocks and the
+    /// remaining vertices corresponds to the cut vertices in the returned vector, in the same
+    /// order.
+    pub fn block_cut_tree(&self, g: &G) -> (StaticGraph, Vec<Vertex<G>>) {
+        let mut blocks: DefaultVertexPropMut<G, Vec<usize>> = g.vertex_prop(vec![]);
+        for (e, u, v) in g.edges_with_ends() {
+            blocks[u].push(self.comp[e]);
+            blocks[v].push(self.comp[e]);
+        }
+        let mut cuts = vec![];
+        let mut edges = vec![];
+        for v in g.vertices() {
+            let bs = &mut blocks[v];
+            bs.sort();
+            bs.dedup();
+            if bs.len() > 1 {
+                let id = self.cur + cuts.len();
+                edges.extend(bs.iter().map(|&b| (b, id)));
+                cuts.push(v);
+            }
+        }
+        let tree = StaticGraph::new_with_edges(self.cur + cuts.len(), edges);
+        (tree, cuts)
+    }
+}
+
+/// The 2-edge-connected components of a graph, created by
+/// [`Components::two_edge_connected_components`].
+///
+/// [`Components::two_edge_connected_components`]:
+/// trait.Components.html#method.two_edge_connected_components
+pub struct TwoEdgeConnectedComponents<G: Graph> {
+    low: LowLink<G>,
+    stack: Vec<Vertex<G>>,
+    comp: DefaultVertexPropMut<G, usize>,
+    cur: usize,
+}
+
+impl<G: Graph> Visitor<G> for TwoEdgeConnectedComponents<G> {
+    fn finish_root_vertex(&mut self, _g: &G, v: Vertex<G>) -> Control {
+        self.pop_component(v);
+        Control::Continue
+    }
+
+    fn discover_vertex(&mut self, _g: &G, v: Vertex<G>) -> Control {
+        self.low.discover_vertex(v);
+        self.stack.push(v);
+        Control::Continue
+    }
+
+    fn discover_back_edge(&mut self, g: &G, e: Edge<G>) -> Control {
+        let (u, v) = g.ends(e);
+        self.low.back_edge(u, v);
+        Control::Continue
+    }
+
+    fn finish_tree_edge(&mut self, g: &G, e: Edge<G>) -> Control {
+        let (u, v) = g.ends(e);
+        self.low.finish_tree_edge(u, v);
+        if self.low.is_cut_edge(u, v) {
+            // e is a cut edge, so the subtree of v is a component
+            self.pop_component(v);
+        }
+        Control::Continue
+    }
+}
+
+impl<G: Graph> TwoEdgeConnectedComponents<G> {
+    /// Returns `true` if `u` and `v` are in the same component.
+    pub fn is_connected(&self, u: Vertex<G>, v: Vertex<G>) -> bool {
+        self.comp[u] == self.comp[v]
+    }
+
+    /// Returns `true` if `u` and `v` are in different components.
+    pub fn is_disconnected(&self, u: Vertex<G>, v: Vertex<G>) -> bool {
+        self.comp[u] != self.comp[v]
+    }
+
+    /// Returns the component of the vertex `v`, a number in `0..num_components()`.
+    pub fn component(&self, v: Vertex<G>) -> usize {
+        self.comp[v]
+    }
+
+    /// Returns the number of components.
+    pub fn num_components(&self) -> usize {
+        self.cur
+    }
+
+    /// Returns the vertex property that maps each vertex to its component.
+    pub fn into_components(self) -> DefaultVertexPropMut<G, usize> {
+        self.comp
+    }
+
+    // Pops the vertices of the stack until v and puts them in a new component.
+    fn pop_component(&mut self, v: Vertex<G>) {
+        loop {
+            let u = self.stack.pop().unwrap();
+            self.comp[u] = self.cur;
+            if u == v {
+                break;
+            }
+        }
+        self.cur += 1;
+    }
+}
+
 #[doc(hidden)]
 pub fn cut_vertices_naive<G: IncidenceGraph>(g: &G) -> Vec<Vertex<G>> {
     vec(g.vertices().filter(|&v| is_cut_vertex_naive(g, v)))
@@ -286,6 +751,7 @@ fn is_cut_edge_naive<G: IncidenceGraph>(g: &G, e: Edge<G>) -> bool {
 #[cfg(test)]
 mod tests {
     use super::{cut_edges_naive, cut_vertices_naive, Components};
+    use fera_fun::vec;
     use prelude::*;
 
     #[test]
@@ -368,6 +834,112 @@ mod tests {
         assert_eq!(exp, sorted_ends(&g, g.cut_edges()));
     }
 
+    #[test]
+    fn biconnected_components() {
+        // 0       3
+        // | \   /   \
+        // |   1      5
+        // | / | \   /
+        // 2   6   4
+        let g: StaticGraph = graph!(
+            7,
+            (0, 1),
+            (0, 2),
+            (1, 2),
+            (1, 3),
+            (1, 4),
+            (1, 6),
+            (3, 5),
+            (4, 5)
+        );
+        let e = vec(g.edges());
+        let bcc = g.biconnected_components();
+        assert_eq!(3, bcc.num_components());
+        let blocks = vec![vec![0, 1, 2], vec![3, 4, 6, 7], vec![5]];
+        for block in &blocks {
+            for &a in block {
+                for &b in block {
+                    assert_eq!(bcc.component(e[a]), bcc.component(e[b]));
+                }
+            }
+        }
+        let (tree, cuts) = bcc.block_cut_tree(&g);
+        assert_eq!(vec![1], cuts);
+        assert_eq!(3, tree.num_edges());
+        assert_eq!(vec![3, 3, 3], vec(tree.edges_ends().map(|(_, c)| c)));
+
+        let comps = g.two_edge_connected_components();
+        assert_eq!(2, comps.num_components());
+        let v = vec(g.vertices());
+        assert!(comps.is_connected(v[0], v[5]));
+        assert!(comps.is_disconnected(v[1], v[6]));
+
+        // the loop is a block by itself
+        let g: StaticGraph = graph!(3, (0, 1), (1, 2), (1, 1));
+        let e = vec(g.edges());
+        let bcc = g.biconnected_components();
+        assert_eq!(3, bcc.num_components());
+        assert_eq!(
+            vec![0, 1, 2],
+            sorted(vec(e.iter().map(|&e| bcc.component(e))))
+        );
+        let (tree, cuts) = bcc.block_cut_tree(&g);
+        assert_eq!(vec![1], cuts);
+        assert_eq!(4, tree.num_vertices());
+        assert_eq!(3, tree.num_edges());
+    }
+
+    #[test]
+    fn strongly_connected_components() {
+        // Example from CLRS
+        //
+        // 0 --> 1 --> 2 <-> 3
+        // ^   / |     |     |
+        // |  /  |     |     |
+        // | v   v     v     v
+        // 4 --> 5 <-> 6 --> 7
+        let g: StaticDigraph = graph!(
+            8,
+            (0, 1),
+            (1, 2),
+            (1, 4),
+            (1, 5),
+            (2, 3),
+            (2, 6),
+            (3, 2),
+            (3, 7),
+            (4, 0),
+            (4, 5),
+            (5, 6),
+            (6, 5),
+            (6, 7)
+        );
+        let v = vec(g.vertices());
+        let scc = g.strongly_connected_components();
+        assert_eq!(4, scc.num_components());
+        let groups = vec![vec![0, 1, 4], vec![2, 3], vec![5, 6], vec![7]];
+        for group in &groups {
+            for &a in group {
+                for &b in group {
+                    assert!(scc.is_connected(v[a], v[b]));
+                }
+            }
+        }
+        let comp = vec(groups.iter().map(|group| scc.component(v[group[0]])));
+        assert_eq!(vec![3, 2, 1, 0], comp);
+        assert!(!g.is_strongly_connected());
+
+        let dag = scc.condensation(&g);
+        assert_eq!(
+            vec![(1, 0), (2, 0), (2, 1), (3, 1), (3, 2)],
+            sorted(
+                dag.edges_ends()
+                    .map(|(a, b)| (a as usize, b as usize))
+                    .collect()
+            )
+        );
+    }
+
     fn sorted<T: Ord>(mut v: Vec<T>) -> Vec<T> {
         v.sort();
         v
@@ -0,0 +1,242 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Vertex and edge connectivity of undirected graphs.
+//!
+//! The vertex (edge) connectivity is the minimum number of vertices (edges) whose removal
+//! disconnects the graph. The algorithms compute the connectivity with [`MaxFlow`] and return a
+//! minimum separator as witness, so a graph with connectivity `k` remains connected after the
+//! failure of any `k - 1` vertices (edges), but not after the failure of the returned ones.
+//!
+//! [`MaxFlow`]: ../flow/trait.MaxFlow.html
+
+use algs::index::vertex_index;
+use algs::MaxFlow;
+use prelude::*;
+
+pub trait Connectivity: Incidence {
+    /// Returns the vertex connectivity of the graph and a minimum set of vertices whose removal
+    /// disconnects the graph.
+    ///
+    /// A graph where each pair of distinct vertices is adjacent cannot be disconnected, in this
+    /// case the connectivity is `n - 1` and the returned vertices are all vertices except one
+    /// (their removal leaves a trivial graph). The connectivity of a disconnected graph is `0`.
+    ///
+    /// The algorithm (by Even) computes `O(k n)` maximum flows in a digraph where each vertex is
+    /// split in two, where `k` is the vertex connectivity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate fera_graph;
+    ///
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::Connectivity;
+    ///
+    /// # fn main() {
+    /// // two complete graphs with 4 vertices that share the vertices 2 and 3
+    /// let g: StaticGraph = graph!(
+    ///     6,
+    ///     (0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3),
+    ///     (2, 4), (2, 5), (3, 4), (3, 5), (4, 5)
+    /// );
+    /// let (k, mut separator) = g.vertex_connectivity();
+    /// separator.sort();
+    /// assert_eq!(2, k);
+    /// assert_eq!(vec![2, 3], separator);
+    /// assert!(g.is_k_connected(2));
+    /// assert!(!g.is_k_connected(3));
+    /// # }
+    /// ```
+    fn vertex_connectivity(&self) -> (usize, Vec<Vertex<Self>>)
+    where
+        Self: Graph,
+    {
+        let vertices: Vec<_> = self.vertices().collect();
+        let n = vertices.len();
+        if n == 0 {
+            return (0, vec![]);
+        }
+        let index = vertex_index(self, &vertices);
+        let mut adj = vec![vec![]; n];
+        // the vertex v is split in 2 * v (in) and 2 * v + 1 (out), the arcs of the edges are never
+        // in a minimum cut because their capacity is greater than the connectivity
+        let mut arcs: Vec<_> = (0..n).map(|v| (2 * v, 2 * v + 1, 1)).collect();
+        for (u, v) in self.edges_ends() {
+            let (u, v) = (index[u], index[v]);
+            if u != v {
+                adj[u].push(v);
+                adj[v].push(u);
+                arcs.push((2 * u + 1, 2 * v, n));
+                arcs.push((2 * v + 1, 2 * u, n));
+            }
+        }
+        let (net, cap) = StaticDigraph::new_with_edges_prop(2 * n, &arcs);
+
+        let mut best = n - 1;
+        let mut separator = vertices[..n - 1].to_vec();
+        // some of the first k + 1 vertices is not in a minimum separator, so it is enough to
+        // compute the flows from these vertices to the non adjacent ones
+        let mut s = 0;
+        while s <= best && s < n {
+            let mut adjacent = vec![false; n];
+            adjacent[s] = true;
+            for &v in &adj[s] {
+                adjacent[v] = true;
+            }
+            for t in (0..n).filter(|&t| !adjacent[t]) {
+                let flow = net.dinic(2 * s as u32 + 1, 2 * t as u32, &cap);
+                if flow.value < best {
+                    let mut reached = vec![false; 2 * n];
+                    for v in flow.source_side {
+                        reached[v as usize] = true;
+                    }
+                    best = flow.value;
+                    separator = (0..n)
+                        .filter(|&v| reached[2 * v] && !reached[2 * v + 1])
+                        .map(|v| vertices[v])
+                        .collect();
+                    if best == 0 {
+                        return (best, separator);
+                    }
+                }
+            }
+            s += 1;
+        }
+        (best, separator)
+    }
+
+    /// Returns the edge connectivity of the graph and a minimum set of edges whose removal
+    /// disconnects the graph. The connectivity of a graph with less than two vertices or of a
+    /// disconnected graph is `0`.
+    ///
+    /// The algorithm computes `n - 1` maximum flows from a fixed vertex to each of the others.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate fera_graph;
+    ///
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::Connectivity;
+    ///
+    /// # fn main() {
+    /// // two complete graphs with 4 vertices connected by two edges
+    /// let g: StaticGraph = graph!(
+    ///     8,
+    ///     (0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3),
+    ///     (4, 5), (4, 6), (4, 7), (5, 6), (5, 7), (6, 7),
+    ///     (0, 4), (3, 7)
+    /// );
+    /// let (k, cut) = g.edge_connectivity();
+    /// assert_eq!(2, k);
+    /// let mut cut: Vec<_> = cut.into_iter().map(|e| g.ends(e)).collect();
+    /// cut.sort();
+    /// assert_eq!(vec![(0, 4), (3, 7)], cut);
+    /// assert!(g.is_k_edge_connected(2));
+    /// assert!(!g.is_k_edge_connected(3));
+    /// # }
+    /// ```
+    fn edge_connectivity(&self) -> (usize, Vec<Edge<Self>>)
+    where
+        Self: Graph,
+    {
+        let vertices: Vec<_> = self.vertices().collect();
+        let n = vertices.len();
+        if n < 2 {
+            return (0, vec![]);
+        }
+        let index = vertex_index(self, &vertices);
+        let mut arcs = vec![];
+        for (u, v) in self.edges_ends() {
+            let (u, v) = (index[u], index[v]);
+            arcs.push((u, v, 1));
+            arcs.push((v, u, 1));
+        }
+        let (net, cap) = StaticDigraph::new_with_edges_prop(n, &arcs);
+
+        let mut best = usize::max_value();
+        let mut side = vec![];
+        for t in 1..n {
+            let flow = net.dinic(0, t as u32, &cap);
+            if flow.value < best {
+                best = flow.value;
+                side = vec![false; n];
+                for v in flow.source_side {
+                    side[v as usize] = true;
+                }
+                if best == 0 {
+                    break;
+                }
+            }
+        }
+        let cut = self
+            .edges_with_ends()
+            .filter(|&(_, u, v)| side[index[u]] != side[index[v]])
+            .map(|(e, _, _)| e)
+            .collect();
+        (best, cut)
+    }
+
+    /// Returns `true` if the graph is `k`-vertex-connected, that is, if its vertex connectivity
+    /// is at least `k`. See [`vertex_connectivity`].
+    ///
+    /// [`vertex_connectivity`]: #method.vertex_connectivity
+    fn is_k_connected(&self, k: usize) -> bool
+    where
+        Self: Graph,
+    {
+        k == 0 || self.vertex_connectivity().0 >= k
+    }
+
+    /// Returns `true` if the graph is `k`-edge-connected, that is, if its edge connectivity is at
+    /// least `k`. See [`edge_connectivity`].
+    ///
+    /// [`edge_connectivity`]: #method.edge_connectivity
+    fn is_k_edge_connected(&self, k: usize) -> bool
+    where
+        Self: Graph,
+    {
+        k == 0 || self.edge_connectivity().0 >= k
+    }
+}
+
+impl<G: Incidence> Connectivity for G {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vertex_connectivity() {
+        let g: StaticGraph = graph!(4, (0, 1), (1, 2), (2, 3), (1, 1), (1, 2));
+        assert_eq!((1, vec![1]), g.vertex_connectivity());
+
+        let g = CompleteGraph::new(4);
+        assert_eq!((3, vec![0, 1, 2]), g.vertex_connectivity());
+
+        let g: StaticGraph = graph!(3, (0, 1));
+        assert_eq!((0, vec![]), g.vertex_connectivity());
+        assert!(g.is_k_connected(0));
+        assert!(!g.is_k_connected(1));
+    }
+
+    #[test]
+    fn edge_connectivity() {
+        // a cycle with the edge (1, 2) doubled
+        let g: StaticGraph = graph!(4, (0, 1), (1, 2), (2, 3), (3, 0), (1, 2), (3, 3));
+        let (k, cut) = g.edge_connectivity();
+        assert_eq!(2, k);
+        let cut: Vec<(u32, u32)> = cut.into_iter().map(|e| g.ends(e)).collect();
+        assert_eq!(2, cut.len());
+        assert!(!cut.contains(&(1, 2)) && !cut.contains(&(3, 3)));
+        assert!(g.is_k_edge_connected(2));
+        assert!(!g.is_k_edge_connected(3));
+
+        let g: StaticGraph = graph!(1);
+        assert_eq!((0, vec![]), g.edge_connectivity());
+    }
+}
@@ -2,12 +2,17 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
-//! Cycles related algorithms, including testing if a graph is acyclic.
+//! Cycles related algorithms, including testing if a graph is acyclic and [topological sorting].
+//!
+//! [topological sorting]: https://en.wikipedia.org/wiki/Topological_sorting
 
 use prelude::*;
 use props::Color;
 use traverse::*;
 
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
 pub trait Cycles: Incidence {
     fn is_acyclic(&self) -> bool
     where
@@ -27,6 +32,100 @@ pub trait Cycles: Incidence {
         dag
     }
 
+    /// Returns the vertices of the digraph in topological order, that is, for every edge `(u,
+    /// v)`, `u` comes before `v`. If the digraph has a cycle, the edges of one directed cycle are
+    /// returned as an error.
+    ///
+    /// The order is computed with a depth first search, so it runs in `O(n + m)` time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate fera_graph;
+    ///
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::Cycles;
+    ///
+    /// # fn main() {
+    /// let g: StaticDigraph = graph!(3, (2, 0), (0, 1), (2, 1));
+    /// assert_eq!(vec![2, 0, 1], g.topological_sort().unwrap());
+    ///
+    /// let g: StaticDigraph = graph!(3, (0, 1), (1, 2), (2, 1));
+    /// let cycle = g.topological_sort().unwrap_err();
+    /// assert_eq!(vec![(1, 2), (2, 1)], g.ends(cycle).collect::<Vec<_>>());
+    /// # }
+    /// ```
+    fn topological_sort(&self) -> Result<Vec<Vertex<Self>>, Vec<Edge<Self>>>
+    where
+        Self: Digraph,
+    {
+        let mut vis = TopologicalSort {
+            order: vec![],
+            parent: self.vertex_prop(Self::edge_none()),
+            cycle: None,
+        };
+        self.dfs(&mut vis).run();
+        if let Some(cycle) = vis.cycle {
+            Err(cycle)
+        } else {
+            vis.order.reverse();
+            Ok(vis.order)
+        }
+    }
+
+    /// Similar to `topological_sort` but returns the lexicographically smallest topological
+    /// order, that is, among the vertices that can be the next in the order, the smallest one is
+    /// selected.
+    ///
+    /// The order is computed with the Kahn's algorithm using a binary heap, so it runs in `O(n
+    /// log n + m)` time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate fera_graph;
+    ///
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::Cycles;
+    ///
+    /// # fn main() {
+    /// let g: StaticDigraph = graph!(4, (3, 0), (2, 0), (1, 3));
+    /// assert_eq!(vec![1, 2, 3, 0], g.lexicographic_topological_sort().unwrap());
+    /// # }
+    /// ```
+    fn lexicographic_topological_sort(&self) -> Result<Vec<Vertex<Self>>, Vec<Edge<Self>>>
+    where
+        Self: Digraph,
+        Vertex<Self>: Ord,
+    {
+        let mut in_degree = self.default_vertex_prop(0usize);
+        for v in self.edges().map(|e| self.target(e)) {
+            in_degree[v] += 1;
+        }
+        let mut heap = BinaryHeap::new();
+        for v in self.vertices().filter(|&v| in_degree[v] == 0) {
+            heap.push(Reverse(v));
+        }
+        let mut order = Vec::with_capacity(self.num_vertices());
+        while let Some(Reverse(u)) = heap.pop() {
+            order.push(u);
+            for v in self.out_neighbors(u) {
+                in_degree[v] -= 1;
+                if in_degree[v] == 0 {
+                    heap.push(Reverse(v));
+                }
+            }
+        }
+        if order.len() == self.num_vertices() {
+            Ok(order)
+        } else {
+            // the remaining vertices are in or reachable from a cycle, let the dfs find one
+            Err(self.topological_sort().unwrap_err())
+        }
+    }
+
     fn is_cycle_graph(&self) -> bool
     where
         Self: VertexList + EdgeList + WithVertexProp<Color>,
@@ -88,9 +187,70 @@ impl<'a, G: WithEdge> Visitor<G> for IsDag<'a> {
     }
 }
 
+pub struct TopologicalSort<G: Digraph> {
+    order: Vec<Vertex<G>>,
+    parent: DefaultVertexPropMut<G, OptionEdge<G>>,
+    cycle: Option<Vec<Edge<G>>>,
+}
+
+impl<G: Digraph> Visitor<G> for TopologicalSort<G> {
+    fn discover_tree_edge(&mut self, g: &G, e: Edge<G>) -> Control {
+        self.parent[g.target(e)] = e.into();
+        Control::Continue
+    }
+
+    fn discover_back_edge(&mut self, g: &G, e: Edge<G>) -> Control {
+        // v is an ancestor of u, so the tree path from v to u and e form a cycle
+        let (u, v) = g.ends(e);
+        let mut cycle = vec![e];
+        let mut cur = u;
+        while cur != v {
+            let f = self.parent[cur].into_option().unwrap();
+            cycle.push(f);
+            cur = g.source(f);
+        }
+        cycle.reverse();
+        self.cycle = Some(cycle);
+        Control::Break
+    }
+
+    fn finish_vertex(&mut self, _g: &G, v: Vertex<G>) -> Control {
+        self.order.push(v);
+        Control::Continue
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use algs::Paths;
+    use fera_fun::vec;
+
+    #[test]
+    fn topological_sort() {
+        let g: StaticDigraph = graph!(6, (5, 2), (5, 0), (4, 0), (4, 1), (2, 3), (3, 1));
+        let order = g.topological_sort().unwrap();
+        let mut pos = g.default_vertex_prop(0);
+        for (i, &v) in order.iter().enumerate() {
+            pos[v] = i;
+        }
+        assert_eq!(6, order.len());
+        assert!(g.edges_ends().all(|(u, v)| pos[u] < pos[v]));
+        assert_eq!(
+            vec![4, 5, 0, 2, 3, 1],
+            g.lexicographic_topological_sort().unwrap()
+        );
+    }
+
+    #[test]
+    fn topological_sort_cycle() {
+        let g: StaticDigraph = graph!(5, (0, 1), (1, 2), (2, 3), (3, 1), (3, 4));
+        let e = vec(g.edges());
+        let exp = vec![e[1], e[2], e[3]];
+        assert_eq!(exp, g.topological_sort().unwrap_err());
+        assert_eq!(exp, g.lexicographic_topological_sort().unwrap_err());
+        assert!(g.is_walk(&exp));
+    }
 
     #[test]
     fn is_cycle_graph() {
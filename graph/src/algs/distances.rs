@@ -2,12 +2,36 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
-use fun::max_prop;
+//! Distances related algorithms, including eccentricity, radius, diameter, center, periphery and
+//! all pairs shortest paths ([Floyd-Warshall] and [Johnson]).
+//!
+//! The eccentricity of a vertex `v` is the greatest distance from `v` to any other vertex. It is
+//! infinite (represented by `None` in an [`OptionalMax`]) if some vertex is not reachable from
+//! `v`. The radius and the diameter are the minimum and the maximum eccentricity. The center and
+//! the periphery are the vertices with eccentricity equal to the radius and to the diameter.
+//!
+//! The unweighted versions consider that every edge has length 1 and are computed using a breadth
+//! first search. The weighted versions use Dijkstra's algorithm, so the weights must be non
+//! negative.
+//!
+//! [Floyd-Warshall]: https://en.wikipedia.org/wiki/Floyd–Warshall_algorithm
+//! [Johnson]: https://en.wikipedia.org/wiki/Johnson's_algorithm
+//! [`OptionalMax`]: https://docs.rs/fera-optional/0.2/fera_optional/type.OptionalMax.html
+
+use algs::shortest_paths::{bellman_ford_rounds, path_to, NegativeCycle, ShortestPaths};
+use fun::{max_prop, min_prop};
 use prelude::*;
 use props::{Color, FnProp};
 use traverse::*;
 
+use fera_optional::OptionalMax;
+use num_traits::{Bounded, Zero};
+
+use std::ops::{Index, Sub};
+
 pub trait Distances: Incidence {
+    /// Returns the diameter of the graph or `usize::max_value()` if the graph is not connected
+    /// (strongly connected for digraphs).
     fn diameter(&self) -> usize
     where
         Self: VertexList + WithVertexProp<usize> + WithVertexProp<Color>,
@@ -16,12 +40,589 @@ pub trait Distances: Incidence {
         self.vertices()
             .map(|v| {
                 dist.set_values(self.vertices(), usize::max_value());
-                self.dfs(RecordDistance(&mut dist)).root(v).run();
+                self.bfs(RecordDistance(&mut dist)).root(v).run();
                 max_prop(FnProp(|x| dist[x]), self.vertices()).unwrap()
             })
             .max()
             .unwrap_or(0)
     }
+
+    /// Returns the eccentricity of `v`, that is, the greatest number of edges in a shortest path
+    /// from `v` to any other vertex.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate fera_graph;
+    ///
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::Distances;
+    ///
+    /// # fn main() {
+    /// let g: StaticGraph = graph!(4, (0, 1), (1, 2), (2, 3));
+    /// let v: Vec<_> = g.vertices().collect();
+    /// assert_eq!(Some(3), g.eccentricity(v[0]).into_option());
+    /// assert_eq!(Some(2), g.eccentricity(v[1]).into_option());
+    ///
+    /// let g: StaticGraph = graph!(3, (0, 1));
+    /// let v: Vec<_> = g.vertices().collect();
+    /// assert_eq!(None, g.eccentricity(v[0]).into_option());
+    /// # }
+    /// ```
+    fn eccentricity(&self, v: Vertex<Self>) -> OptionalMax<usize>
+    where
+        Self: VertexList + WithVertexProp<usize> + WithVertexProp<Color>,
+    {
+        let mut dist = self.default_vertex_prop(usize::max_value());
+        self.bfs(RecordDistance(&mut dist)).root(v).run();
+        max_prop(FnProp(|x| dist[x]), self.vertices())
+            .unwrap()
+            .into()
+    }
+
+    /// Returns a vertex property with the eccentricity of every vertex.
+    fn eccentricities(&self) -> DefaultVertexPropMut<Self, OptionalMax<usize>>
+    where
+        Self: VertexList
+            + WithVertexProp<usize>
+            + WithVertexProp<Color>
+            + WithVertexProp<OptionalMax<usize>>,
+    {
+        let mut ecc = self.default_vertex_prop(OptionalMax::default());
+        for v in self.vertices() {
+            ecc[v] = self.eccentricity(v);
+        }
+        ecc
+    }
+
+    /// Returns the radius of the graph, that is, the minimum eccentricity. The radius of a graph
+    /// without vertices is zero.
+    fn radius(&self) -> OptionalMax<usize>
+    where
+        Self: VertexList + WithVertexProp<usize> + WithVertexProp<Color>,
+    {
+        self.vertices()
+            .map(|v| self.eccentricity(v))
+            .min()
+            .unwrap_or_else(|| 0.into())
+    }
+
+    /// Returns the vertices with minimum eccentricity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate fera_graph;
+    ///
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::Distances;
+    ///
+    /// # fn main() {
+    /// let g: StaticGraph = graph!(5, (0, 1), (1, 2), (2, 3), (3, 4));
+    /// let v: Vec<_> = g.vertices().collect();
+    /// assert_eq!(vec![v[2]], g.center());
+    /// assert_eq!(vec![v[0], v[4]], g.periphery());
+    /// # }
+    /// ```
+    fn center(&self) -> Vec<Vertex<Self>>
+    where
+        Self: VertexList
+            + WithVertexProp<usize>
+            + WithVertexProp<Color>
+            + WithVertexProp<OptionalMax<usize>>,
+    {
+        let ecc = self.eccentricities();
+        let radius = min_prop(FnProp(|v| ecc[v]), self.vertices());
+        vertices_with(self, &ecc, radius)
+    }
+
+    /// Returns the vertices with maximum eccentricity.
+    fn periphery(&self) -> Vec<Vertex<Self>>
+    where
+        Self: VertexList
+            + WithVertexProp<usize>
+            + WithVertexProp<Color>
+            + WithVertexProp<OptionalMax<usize>>,
+    {
+        let ecc = self.eccentricities();
+        let diameter = max_prop(FnProp(|v| ecc[v]), self.vertices());
+        vertices_with(self, &ecc, diameter)
+    }
+
+    /// Returns the eccentricity of `v` using the (non negative) edge weights `w`, that is, the
+    /// greatest distance from `v` to any other vertex.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate fera_graph;
+    ///
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::Distances;
+    ///
+    /// # fn main() {
+    /// let (g, w): (StaticGraph, _) = graph!(
+    ///     4,
+    ///     (0, 1) -> 5u32,
+    ///     (0, 2) -> 1,
+    ///     (2, 3) -> 1,
+    ///     (3, 1) -> 1
+    /// );
+    /// let v: Vec<_> = g.vertices().collect();
+    /// assert_eq!(Some(3), g.weighted_eccentricity(v[0], &w).into_option());
+    /// assert_eq!(Some(2), g.weighted_radius(&w).into_option());
+    /// assert_eq!(Some(3), g.weighted_diameter(&w).into_option());
+    /// # }
+    /// ```
+    fn weighted_eccentricity<W, T>(&self, v: Vertex<Self>, w: W) -> OptionalMax<T>
+    where
+        Self: VertexList + WithVertexProp<T> + WithVertexProp<OptionEdge<Self>>,
+        W: EdgePropGet<Self, T>,
+        T: Copy + Ord + Zero + Bounded,
+    {
+        let (dist, _) = self.dijkstra(v, w).run();
+        max_prop(FnProp(|x| dist[x]), self.vertices())
+            .unwrap()
+            .into()
+    }
+
+    /// Returns a vertex property with the weighted eccentricity of every vertex.
+    fn weighted_eccentricities<W, T>(&self, w: W) -> DefaultVertexPropMut<Self, OptionalMax<T>>
+    where
+        Self: VertexList
+            + WithVertexProp<T>
+            + WithVertexProp<OptionEdge<Self>>
+            + WithVertexProp<OptionalMax<T>>,
+        W: EdgePropGet<Self, T>,
+        T: Copy + Ord + Zero + Bounded,
+    {
+        let mut ecc = self.default_vertex_prop(OptionalMax::default());
+        for v in self.vertices() {
+            ecc[v] = self.weighted_eccentricity(v, &w);
+        }
+        ecc
+    }
+
+    /// Returns the weighted radius of the graph, that is, the minimum weighted eccentricity. The
+    /// radius of a graph without vertices is zero.
+    fn weighted_radius<W, T>(&self, w: W) -> OptionalMax<T>
+    where
+        Self: VertexList + WithVertexProp<T> + WithVertexProp<OptionEdge<Self>>,
+        W: EdgePropGet<Self, T>,
+        T: Copy + Ord + Zero + Bounded,
+    {
+        self.vertices()
+            .map(|v| self.weighted_eccentricity(v, &w))
+            .min()
+            .unwrap_or_else(|| T::zero().into())
+    }
+
+    /// Returns the weighted diameter of the graph, that is, the maximum weighted eccentricity. The
+    /// diameter of a graph without vertices is zero.
+    fn weighted_diameter<W, T>(&self, w: W) -> OptionalMax<T>
+    where
+        Self: VertexList + WithVertexProp<T> + WithVertexProp<OptionEdge<Self>>,
+        W: EdgePropGet<Self, T>,
+        T: Copy + Ord + Zero + Bounded,
+    {
+        self.vertices()
+            .map(|v| self.weighted_eccentricity(v, &w))
+            .max()
+            .unwrap_or_else(|| T::zero().into())
+    }
+
+    /// Returns the vertices with minimum weighted eccentricity.
+    fn weighted_center<W, T>(&self, w: W) -> Vec<Vertex<Self>>
+    where
+        Self: VertexList
+            + WithVertexProp<T>
+            + WithVertexProp<OptionEdge<Self>>
+            + WithVertexProp<OptionalMax<T>>,
+        W: EdgePropGet<Self, T>,
+        T: Copy + Ord + Zero + Bounded,
+    {
+        let ecc = self.weighted_eccentricities(w);
+        let radius = min_prop(FnProp(|v| ecc[v]), self.vertices());
+        vertices_with(self, &ecc, radius)
+    }
+
+    /// Returns the vertices with maximum weighted eccentricity.
+    fn weighted_periphery<W, T>(&self, w: W) -> Vec<Vertex<Self>>
+    where
+        Self: VertexList
+            + WithVertexProp<T>
+            + WithVertexProp<OptionEdge<Self>>
+            + WithVertexProp<OptionalMax<T>>,
+        W: EdgePropGet<Self, T>,
+        T: Copy + Ord + Zero + Bounded,
+    {
+        let ecc = self.weighted_eccentricities(w);
+        let diameter = max_prop(FnProp(|v| ecc[v]), self.vertices());
+        vertices_with(self, &ecc, diameter)
+    }
+
+    /// Computes the shortest paths between all pairs of vertices using the Floyd-Warshall's
+    /// algorithm.
+    ///
+    /// The running time is `O(n^3)`, so this is a good choice for dense graphs, like
+    /// [`CompleteGraph`]. The weights `w` can be negative, but if the graph has a cycle with
+    /// negative weight, one such cycle is returned as an error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::Distances;
+    ///
+    /// let g = CompleteGraph::new(4);
+    /// let mut w = g.default_edge_prop(1u32);
+    /// w[g.edge_by_ends(0, 3)] = 10;
+    /// let apsp = g.floyd_warshall(&w).unwrap();
+    /// assert_eq!(2, apsp[(0, 3)]);
+    /// assert_eq!(2, apsp.path(0, 3).unwrap().len());
+    /// ```
+    ///
+    /// [`CompleteGraph`]: ../../graphs/complete/type.CompleteGraph.html
+    fn floyd_warshall<W, T>(
+        &self,
+        w: W,
+    ) -> Result<AllPairsShortestPaths<Self, T>, NegativeCycle<Self>>
+    where
+        Self: VertexList
+            + EdgeList
+            + WithVertexIndexProp
+            + WithVertexProp<T>
+            + WithVertexProp<OptionEdge<Self>>,
+        W: EdgePropGet<Self, T>,
+        T: Copy + Ord + Zero + Bounded,
+    {
+        let mut apsp = AllPairsShortestPaths::new(self);
+        let n = apsp.n;
+        let index = self.vertex_index();
+        let zero = T::zero();
+        let inf = T::max_value();
+        for v in self.vertices() {
+            let i = index.get(v);
+            apsp.dist[i * n + i] = zero;
+        }
+        for e in self.edges() {
+            let rev = if self.orientation(e).is_undirected() {
+                self.get_reverse(e)
+            } else {
+                None
+            };
+            for e in Some(e).into_iter().chain(rev) {
+                let (u, v) = self.ends(e);
+                let uv = index.get(u) * n + index.get(v);
+                let we = w.get(e);
+                if we < apsp.dist[uv] {
+                    apsp.dist[uv] = we;
+                    apsp.parent[uv] = e.into();
+                }
+            }
+        }
+        for k in 0..n {
+            for i in 0..n {
+                let dik = apsp.dist[i * n + k];
+                if dik == inf {
+                    continue;
+                }
+                for j in 0..n {
+                    let dkj = apsp.dist[k * n + j];
+                    if dkj == inf {
+                        continue;
+                    }
+                    let dij = dik + dkj;
+                    if dij < apsp.dist[i * n + j] {
+                        apsp.dist[i * n + j] = dij;
+                        apsp.parent[i * n + j] = apsp.parent[k * n + j];
+                    }
+                }
+                if apsp.dist[i * n + i] < zero {
+                    // there is a negative cycle reachable from the vertex with index i, stops
+                    // before the distances overflow and let bellman_ford find the cycle
+                    let v = self.vertices().find(|&v| index.get(v) == i).unwrap();
+                    return Err(self.bellman_ford(v, &w).err().unwrap());
+                }
+            }
+        }
+        Ok(apsp)
+    }
+
+    /// Computes the shortest paths between all pairs of vertices using the Johnson's algorithm.
+    ///
+    /// The weights are first reweighted using potentials computed with the Bellman-Ford's
+    /// algorithm, so they became non negative, and then the Dijkstra's algorithm is executed from
+    /// every vertex. The running time is `O(n m log n)`, so this is a good choice for sparse
+    /// graphs, like most [`StaticGraph`]s. If the graph has a cycle with negative weight, one such
+    /// cycle is returned as an error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate fera_graph;
+    ///
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::Distances;
+    ///
+    /// # fn main() {
+    /// let (g, w): (StaticDigraph, _) = graph!(
+    ///     3,
+    ///     (0, 1) -> 2i32,
+    ///     (1, 2) -> -1,
+    ///     (0, 2) -> 3
+    /// );
+    /// let v: Vec<_> = g.vertices().collect();
+    /// let apsp = g.johnson(&w).unwrap();
+    /// assert_eq!(1, apsp[(v[0], v[2])]);
+    /// assert_eq!(i32::max_value(), apsp[(v[2], v[0])]);
+    /// assert_eq!(None, apsp.path(v[2], v[0]));
+    /// # }
+    /// ```
+    ///
+    /// [`StaticGraph`]: ../../graphs/static_/type.StaticGraph.html
+    fn johnson<W, T>(&self, w: W) -> Result<AllPairsShortestPaths<Self, T>, NegativeCycle<Self>>
+    where
+        Self: VertexList
+            + EdgeList
+            + WithVertexIndexProp
+            + WithVertexProp<T>
+            + WithVertexProp<OptionEdge<Self>>,
+        W: EdgePropGet<Self, T>,
+        T: Copy + Ord + Zero + Bounded + Sub<Output = T>,
+    {
+        // Starting all distances with zero is the same as adding a new vertex with a zero weight
+        // edge to every vertex and executing the first round of the Bellman-Ford's algorithm
+        let mut h = self.default_vertex_prop(T::zero());
+        let mut h_parent = self.default_vertex_prop(Self::edge_none());
+        bellman_ford_rounds(self, &w, &mut h, &mut h_parent)?;
+
+        let mut apsp = AllPairsShortestPaths::new(self);
+        let n = apsp.n;
+        let index = self.vertex_index();
+        let inf = T::max_value();
+        let reweighted = FnProp(|e| {
+            let (u, v) = self.ends(e);
+            w.get(e) + h[u] - h[v]
+        });
+        for u in self.vertices() {
+            let (dist, parent) = self.dijkstra(u, &reweighted).run();
+            let i = index.get(u);
+            for v in self.vertices() {
+                if dist[v] != inf {
+                    let j = index.get(v);
+                    apsp.dist[i * n + j] = dist[v] - h[u] + h[v];
+                    apsp.parent[i * n + j] = parent[v];
+                }
+            }
+        }
+        Ok(apsp)
+    }
 }
 
 impl<G: Incidence> Distances for G {}
+
+// Returns the vertices v such that Some(ecc[v]) == value.
+fn vertices_with<G, P, T>(g: &G, ecc: &P, value: Option<T>) -> Vec<Vertex<G>>
+where
+    G: VertexList,
+    P: VertexProp<G, T>,
+    T: PartialEq,
+{
+    g.vertices()
+        .filter(|&v| Some(&ecc[v]) == value.as_ref())
+        .collect()
+}
+
+/// The result of an all pairs shortest paths algorithm.
+///
+/// The distance from `u` to `v` can be obtained with `apsp[(u, v)]`, it is `T::max_value()` if
+/// there is no path from `u` to `v`.
+pub struct AllPairsShortestPaths<'a, G, T>
+where
+    G: 'a + WithEdge + WithVertexIndexProp,
+{
+    g: &'a G,
+    index: VertexIndexProp<G>,
+    n: usize,
+    dist: Vec<T>,
+    // parent[u * n + v] is the last edge of a shortest path from u to v
+    parent: Vec<OptionEdge<G>>,
+}
+
+impl<'a, G, T> AllPairsShortestPaths<'a, G, T>
+where
+    G: 'a + VertexList + WithEdge + WithVertexIndexProp,
+    T: Copy + Bounded,
+{
+    fn new(g: &'a G) -> Self {
+        let n = g.num_vertices();
+        AllPairsShortestPaths {
+            g,
+            index: g.vertex_index(),
+            n,
+            dist: vec![T::max_value(); n * n],
+            parent: vec![G::edge_none(); n * n],
+        }
+    }
+}
+
+impl<'a, G, T> AllPairsShortestPaths<'a, G, T>
+where
+    G: 'a + WithEdge + WithVertexIndexProp,
+{
+    /// Returns the edges of a shortest path from `u` to `v` or `None` if there is no path from `u`
+    /// to `v`. If `u == v` the path is empty.
+    pub fn path(&self, u: Vertex<G>, v: Vertex<G>) -> Option<Vec<Edge<G>>> {
+        let i = self.index.get(u);
+        path_to(self.g, u, v, |cur| {
+            self.parent[i * self.n + self.index.get(cur)]
+        })
+    }
+}
+
+impl<'a, G, T> Index<(Vertex<G>, Vertex<G>)> for AllPairsShortestPaths<'a, G, T>
+where
+    G: 'a + WithEdge + WithVertexIndexProp,
+{
+    type Output = T;
+
+    fn index(&self, (u, v): (Vertex<G>, Vertex<G>)) -> &T {
+        &self.dist[self.index.get(u) * self.n + self.index.get(v)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use algs::Paths;
+    use fera_fun::vec;
+
+    fn graph() -> (StaticDigraph, DefaultEdgePropMut<StaticDigraph, i32>) {
+        graph!(
+            5,
+            (0, 1) -> 3,
+            (0, 2) -> 8,
+            (0, 4) -> -4,
+            (1, 3) -> 1,
+            (1, 4) -> 7,
+            (2, 1) -> 4,
+            (3, 0) -> 2,
+            (3, 2) -> -5,
+            (4, 3) -> 6
+        )
+    }
+
+    fn expected() -> Vec<Vec<i32>> {
+        vec![
+            vec![0, 1, -3, 2, -4],
+            vec![3, 0, -4, 1, -1],
+            vec![7, 4, 0, 5, 3],
+            vec![2, -1, -5, 0, -2],
+            vec![8, 5, 1, 6, 0],
+        ]
+    }
+
+    fn check(
+        g: &StaticDigraph,
+        w: &DefaultEdgePropMut<StaticDigraph, i32>,
+        apsp: &AllPairsShortestPaths<StaticDigraph, i32>,
+    ) {
+        let v = vec(g.vertices());
+        for (i, row) in expected().into_iter().enumerate() {
+            for (j, d) in row.into_iter().enumerate() {
+                assert_eq!(d, apsp[(v[i], v[j])]);
+                let path = apsp.path(v[i], v[j]).unwrap();
+                assert!(g.is_path(&path));
+                assert_eq!(d, path.iter().map(|&e| w[e]).sum::<i32>());
+                if i != j {
+                    assert_eq!(v[i], g.source(path[0]));
+                    assert_eq!(v[j], g.target(*path.last().unwrap()));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn floyd_warshall() {
+        let (g, w) = graph();
+        check(&g, &w, &g.floyd_warshall(&w).unwrap());
+    }
+
+    #[test]
+    fn johnson() {
+        let (g, w) = graph();
+        check(&g, &w, &g.johnson(&w).unwrap());
+    }
+
+    #[test]
+    fn negative_cycle() {
+        let (g, mut w) = graph();
+        let v = vec(g.vertices());
+        w[g.edge_by_ends(v[2], v[1])] = 4;
+        w[g.edge_by_ends(v[1], v[3])] = 0;
+        // 1 -> 3 -> 2 -> 1 has weight -1
+        w[g.edge_by_ends(v[3], v[2])] = -5;
+        let NegativeCycle(cycle) = g.floyd_warshall(&w).err().unwrap();
+        assert!(g.is_walk(&cycle));
+        assert!(cycle.iter().map(|&e| w[e]).sum::<i32>() < 0);
+        let NegativeCycle(cycle) = g.johnson(&w).err().unwrap();
+        assert!(g.is_walk(&cycle));
+        assert!(cycle.iter().map(|&e| w[e]).sum::<i32>() < 0);
+    }
+
+    #[test]
+    fn eccentricity() {
+        // a cycle with a pendant vertex
+        let g: StaticGraph = graph!(6, (0, 1), (1, 2), (2, 3), (3, 4), (4, 0), (4, 5));
+        let v = vec(g.vertices());
+        let ecc = g.eccentricities();
+        let expected = vec![2, 3, 3, 2, 2, 3];
+        for (&u, e) in v.iter().zip(expected) {
+            assert_eq!(Some(e), ecc[u].into_option());
+        }
+        assert_eq!(Some(2), g.radius().into_option());
+        assert_eq!(3, g.diameter());
+        assert_eq!(vec![v[0], v[3], v[4]], g.center());
+        assert_eq!(vec![v[1], v[2], v[5]], g.periphery());
+
+        let g: StaticDigraph = graph!(3, (0, 1), (1, 2));
+        let v = vec(g.vertices());
+        assert_eq!(Some(2), g.eccentricity(v[0]).into_option());
+        assert_eq!(None, g.eccentricity(v[1]).into_option());
+        assert_eq!(Some(2), g.radius().into_option());
+        assert_eq!(usize::max_value(), g.diameter());
+        assert_eq!(vec![v[0]], g.center());
+        assert_eq!(vec![v[1], v[2]], g.periphery());
+    }
+
+    #[test]
+    fn weighted_eccentricity() {
+        let (g, w): (StaticGraph, _) = graph!(
+            5,
+            (0, 1) -> 4u32,
+            (1, 2) -> 1,
+            (2, 3) -> 1,
+            (3, 0) -> 4,
+            (3, 4) -> 2
+        );
+        let v = vec(g.vertices());
+        let ecc = g.weighted_eccentricities(&w);
+        let expected = vec![6, 4, 5, 4, 6];
+        for (&u, e) in v.iter().zip(expected) {
+            assert_eq!(Some(e), ecc[u].into_option());
+        }
+        assert_eq!(Some(4), g.weighted_radius(&w).into_option());
+        assert_eq!(Some(6), g.weighted_diameter(&w).into_option());
+        assert_eq!(vec![v[1], v[3]], g.weighted_center(&w));
+        assert_eq!(vec![v[0], v[4]], g.weighted_periphery(&w));
+
+        let (g, w): (StaticGraph, _) = graph!(3, (0, 1) -> 1u32);
+        assert_eq!(None, g.weighted_radius(&w).into_option());
+        assert_eq!(3, g.weighted_center(&w).len());
+    }
+}
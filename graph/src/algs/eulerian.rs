@@ -0,0 +1,216 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! [Eulerian] paths and circuits for graphs and digraphs.
+//!
+//! The paths and circuits are built with the Hierholzer's algorithm and returned as a sequence of
+//! edges where the target of each edge is the source of the next one, so they can be checked with
+//! [`Paths::is_walk`](../paths/trait.Paths.html#method.is_walk).
+//!
+//! [Eulerian]: https://en.wikipedia.org/wiki/Eulerian_path
+
+use algs::index::vertex_index;
+use prelude::*;
+
+pub trait Eulerian: Incidence {
+    /// Returns `true` if the graph has an Eulerian circuit, that is, a closed walk that uses each
+    /// edge exactly once.
+    fn is_eulerian(&self) -> bool
+    where
+        Self: VertexList + EdgeList + BasicProps,
+        Self::Kind: UniformEdgeKind,
+    {
+        self.eulerian_circuit().is_some()
+    }
+
+    /// Returns `true` if the graph has an Eulerian path, that is, a walk (not necessarily closed)
+    /// that uses each edge exactly once.
+    fn has_eulerian_path(&self) -> bool
+    where
+        Self: VertexList + EdgeList + BasicProps,
+        Self::Kind: UniformEdgeKind,
+    {
+        self.eulerian_path().is_some()
+    }
+
+    /// Returns the edges of an Eulerian circuit or `None` if the graph is not Eulerian.
+    ///
+    /// The running time is `O(n + m)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate fera_graph;
+    ///
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::{Eulerian, Paths};
+    ///
+    /// # fn main() {
+    /// // two triangles that share the vertex 0
+    /// let g: StaticGraph = graph!(5, (0, 1), (1, 2), (2, 0), (0, 3), (3, 4), (4, 0));
+    /// let circuit = g.eulerian_circuit().unwrap();
+    /// assert_eq!(6, circuit.len());
+    /// assert!(g.is_walk(&circuit));
+    /// assert_eq!(g.source(circuit[0]), g.target(circuit[5]));
+    ///
+    /// let g: StaticDigraph = graph!(3, (0, 1), (1, 2), (0, 2));
+    /// assert!(g.eulerian_circuit().is_none());
+    /// # }
+    /// ```
+    fn eulerian_circuit(&self) -> Option<Vec<Edge<Self>>>
+    where
+        Self: VertexList + EdgeList + BasicProps,
+        Self::Kind: UniformEdgeKind,
+    {
+        if !ends(self)?.is_empty() {
+            return None;
+        }
+        let start = self
+            .vertices()
+            .find(|&v| self.out_edges(v).next().is_some());
+        match start {
+            Some(start) => hierholzer(self, start),
+            None => Some(vec![]),
+        }
+    }
+
+    /// Returns the edges of an Eulerian path or `None` if the graph has no Eulerian path. If the
+    /// graph is Eulerian, an Eulerian circuit is returned.
+    ///
+    /// The running time is `O(n + m)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate fera_graph;
+    ///
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::{Eulerian, Paths};
+    ///
+    /// # fn main() {
+    /// let g: StaticDigraph = graph!(3, (0, 1), (1, 2), (2, 0), (0, 2));
+    /// let v: Vec<_> = g.vertices().collect();
+    /// let path = g.eulerian_path().unwrap();
+    /// assert!(g.is_walk(&path));
+    /// assert_eq!((v[0], v[2]), (g.source(path[0]), g.target(path[3])));
+    /// assert!(!g.is_eulerian());
+    /// # }
+    /// ```
+    fn eulerian_path(&self) -> Option<Vec<Edge<Self>>>
+    where
+        Self: VertexList + EdgeList + BasicProps,
+        Self::Kind: UniformEdgeKind,
+    {
+        match ends(self)?.first() {
+            Some(&start) => hierholzer(self, start),
+            None => self.eulerian_circuit(),
+        }
+    }
+}
+
+impl<G: Incidence> Eulerian for G {}
+
+// Returns the vertices that must be the ends of an Eulerian path, that is, the vertices with odd
+// degree (for graphs) or the vertex with out degree equals to the in degree plus 1 followed by the
+// vertex with out degree equals to the in degree minus 1 (for digraphs). Returns None if the
+// degrees does not allow an Eulerian path.
+fn ends<G>(g: &G) -> Option<Vec<Vertex<G>>>
+where
+    G: VertexList + EdgeList + BasicProps,
+    G::Kind: UniformEdgeKind,
+{
+    let mut balance = g.default_vertex_prop(0isize);
+    let ends: Vec<_> = if G::Kind::is_directed() {
+        for (u, v) in g.edges_ends() {
+            balance[u] += 1;
+            balance[v] -= 1;
+        }
+        if g.vertices().any(|v| balance[v].abs() > 1) {
+            return None;
+        }
+        let plus = g.vertices().filter(|&v| balance[v] == 1);
+        let minus = g.vertices().filter(|&v| balance[v] == -1);
+        plus.chain(minus).collect()
+    } else {
+        for (u, v) in g.edges_ends() {
+            balance[u] += 1;
+            balance[v] += 1;
+        }
+        g.vertices().filter(|&v| balance[v] % 2 == 1).collect()
+    };
+    // the number of ends is even, for digraphs the sum of balances is zero
+    if ends.len() <= 2 {
+        Some(ends)
+    } else {
+        None
+    }
+}
+
+// Builds a walk starting at start that uses each edge once. Returns None if some edge is not
+// reachable from start.
+fn hierholzer<G>(g: &G, start: Vertex<G>) -> Option<Vec<Edge<G>>>
+where
+    G: Incidence + VertexList + EdgeList + BasicProps,
+{
+    // in undirected graphs an edge and its reverse have the same prop value, so an edge is marked
+    // as used in both directions
+    let mut used = g.default_edge_prop(false);
+    let index = vertex_index(g, g.vertices());
+    let mut out_edges: Vec<_> = g.vertices().map(|v| g.out_edges(v)).collect();
+    let mut walk = Vec::with_capacity(g.num_edges());
+    let mut stack = vec![(start, G::edge_none())];
+    while let Some(&(u, from)) = stack.last() {
+        let next = out_edges[index[u]].by_ref().find(|&e| !used[e]);
+        if let Some(e) = next {
+            used[e] = true;
+            stack.push((g.target(e), e.into()));
+        } else {
+            stack.pop();
+            if let Some(e) = from.into_option() {
+                walk.push(e);
+            }
+        }
+    }
+    if walk.len() == g.num_edges() {
+        walk.reverse();
+        Some(walk)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use algs::Paths;
+
+    #[test]
+    fn eulerian_circuit() {
+        // a loop and parallel edges
+        let g: StaticGraph = graph!(3, (0, 1), (1, 0), (1, 2), (2, 2), (2, 1));
+        let circuit = g.eulerian_circuit().unwrap();
+        assert_eq!(5, circuit.len());
+        assert!(g.is_walk(&circuit));
+        assert!(g.is_eulerian());
+
+        // disconnected
+        let g: StaticGraph = graph!(6, (0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3));
+        assert!(!g.is_eulerian());
+        assert!(!g.has_eulerian_path());
+    }
+
+    #[test]
+    fn eulerian_path() {
+        let g: StaticGraph = graph!(4, (0, 1), (1, 2), (2, 0), (2, 3));
+        let path = g.eulerian_path().unwrap();
+        assert_eq!(4, path.len());
+        assert!(g.is_walk(&path));
+        assert!(!g.is_eulerian());
+
+        let g: StaticDigraph = graph!(3, (0, 1), (0, 2));
+        assert!(!g.has_eulerian_path());
+    }
+}
@@ -0,0 +1,696 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Network flow algorithms, including maximum flow with [Dinic]'s and [push-relabel] algorithms
+//! and minimum cost flow with the [successive shortest paths] algorithm.
+//!
+//! [Dinic]: https://en.wikipedia.org/wiki/Dinic's_algorithm
+//! [push-relabel]: https://en.wikipedia.org/wiki/Push–relabel_maximum_flow_algorithm
+//! [successive shortest paths]: https://en.wikipedia.org/wiki/Minimum-cost_flow_problem
+
+use algs::index::vertex_index;
+use algs::queue::QueueItem;
+use prelude::*;
+
+use num_traits::{Bounded, Zero};
+
+use std::cmp::min;
+use std::collections::{BinaryHeap, VecDeque};
+use std::ops::{Mul, Neg, Sub};
+
+pub trait MaxFlow: Incidence {
+    /// Computes a maximum flow from `source` to `target` using the Dinic's algorithm with the
+    /// (non negative) edge capacities `cap`.
+    ///
+    /// The running time is `O(n^2 m)`.
+    ///
+    /// # Panics
+    ///
+    /// If `source == target`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate fera_graph;
+    ///
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::MaxFlow;
+    ///
+    /// # fn main() {
+    /// let (g, cap): (StaticDigraph, _) = graph!(
+    ///     4,
+    ///     (0, 1) -> 3u32,
+    ///     (0, 2) -> 2,
+    ///     (1, 2) -> 1,
+    ///     (1, 3) -> 1,
+    ///     (2, 3) -> 4
+    /// );
+    /// let v: Vec<_> = g.vertices().collect();
+    /// let flow = g.dinic(v[0], v[3], &cap);
+    /// assert_eq!(4, flow.value);
+    /// assert_eq!(1, flow.flow[g.edge_by_ends(v[1], v[3])]);
+    /// assert_eq!(vec![v[0], v[1]], flow.source_side);
+    /// # }
+    /// ```
+    fn dinic<C, T>(&self, source: Vertex<Self>, target: Vertex<Self>, cap: C) -> Flow<Self, T>
+    where
+        Self: IncidenceDigraph + WithEdgeProp<T>,
+        C: EdgePropGet<Self, T>,
+        T: Copy + Ord + Zero + Sub<Output = T>,
+    {
+        let mut net = Network::new(self, source, target, &cap);
+        net.dinic();
+        net.into_flow(self)
+    }
+
+    /// Computes a maximum flow from `source` to `target` using the FIFO push-relabel algorithm
+    /// with the (non negative) edge capacities `cap`.
+    ///
+    /// The running time is `O(n^3)`.
+    ///
+    /// # Panics
+    ///
+    /// If `source == target`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate fera_graph;
+    ///
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::MaxFlow;
+    ///
+    /// # fn main() {
+    /// let (g, cap): (StaticDigraph, _) = graph!(
+    ///     4,
+    ///     (0, 1) -> 3u32,
+    ///     (0, 2) -> 2,
+    ///     (1, 2) -> 1,
+    ///     (1, 3) -> 1,
+    ///     (2, 3) -> 4
+    /// );
+    /// let v: Vec<_> = g.vertices().collect();
+    /// let flow = g.push_relabel(v[0], v[3], &cap);
+    /// assert_eq!(4, flow.value);
+    /// assert_eq!(vec![v[0], v[1]], flow.source_side);
+    /// # }
+    /// ```
+    fn push_relabel<C, T>(
+        &self,
+        source: Vertex<Self>,
+        target: Vertex<Self>,
+        cap: C,
+    ) -> Flow<Self, T>
+    where
+        Self: IncidenceDigraph + WithEdgeProp<T>,
+        C: EdgePropGet<Self, T>,
+        T: Copy + Ord + Zero + Sub<Output = T>,
+    {
+        let mut net = Network::new(self, source, target, &cap);
+        net.push_relabel();
+        net.into_flow(self)
+    }
+}
+
+impl<G: Incidence> MaxFlow for G {}
+
+/// The result of a maximum flow algorithm.
+pub struct Flow<G: WithEdgeProp<T>, T> {
+    /// The value of the flow, that is, the net amount of flow that leaves the source.
+    pub value: T,
+    /// The amount of flow in each edge.
+    pub flow: DefaultEdgePropMut<G, T>,
+    /// The vertices reachable from the source in the residual graph. The edges that leave this
+    /// set form a minimum cut.
+    pub source_side: Vec<Vertex<G>>,
+}
+
+pub trait MinCostFlow: Incidence {
+    /// Computes a minimum cost flow that satisfies the vertex `supply` using the successive
+    /// shortest paths algorithm with the (non negative) edge capacities `cap` and the (non
+    /// negative) edge costs `cost`.
+    ///
+    /// A vertex with positive supply is a source of flow and a vertex with negative supply (a
+    /// demand) is a sink of flow. Returns `Err(Infeasible)` if the sum of the supplies is not zero
+    /// or if there is no flow that satisfies all supplies and demands.
+    ///
+    /// The running time is `O(U m log n)`, where `U` is the sum of the positive supplies.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate fera_graph;
+    ///
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::MinCostFlow;
+    /// use fera_graph::algs::flow::Infeasible;
+    ///
+    /// # fn main() {
+    /// let g: StaticDigraph = graph!(3, (0, 1), (0, 2), (1, 2));
+    /// let v: Vec<_> = g.vertices().collect();
+    /// let cap = g.default_edge_prop(2i32);
+    /// let mut cost = g.default_edge_prop(1i32);
+    /// cost[g.edge_by_ends(v[0], v[2])] = 3;
+    /// let mut supply = g.default_vertex_prop(0i32);
+    /// supply[v[0]] = 3;
+    /// supply[v[2]] = -3;
+    /// let flow = g.min_cost_flow(&cap, &cost, &supply).unwrap();
+    /// assert_eq!(3, flow.value);
+    /// assert_eq!(7, flow.cost);
+    /// assert_eq!(1, flow.flow[g.edge_by_ends(v[0], v[2])]);
+    ///
+    /// supply[v[0]] = 5;
+    /// supply[v[2]] = -5;
+    /// assert_eq!(Err(Infeasible), g.min_cost_flow(&cap, &cost, &supply).map(|f| f.cost));
+    /// # }
+    /// ```
+    fn min_cost_flow<C, W, S, T>(
+        &self,
+        cap: C,
+        cost: W,
+        supply: S,
+    ) -> Result<CostFlow<Self, T>, Infeasible>
+    where
+        Self: IncidenceDigraph + WithEdgeProp<T>,
+        C: EdgePropGet<Self, T>,
+        W: EdgePropGet<Self, T>,
+        S: VertexPropGet<Self, T>,
+        T: Copy + Ord + Zero + Bounded + Sub<Output = T> + Mul<Output = T> + Neg<Output = T>,
+    {
+        let (mut net, index) = Network::from_edges(self, &cap);
+        net.source = net.add_vertex();
+        net.target = net.add_vertex();
+        let (mut total_supply, mut total_demand) = (T::zero(), T::zero());
+        for v in self.vertices() {
+            let s = supply.get(v);
+            if s > T::zero() {
+                net.add_arc(net.source, index[v], s);
+                total_supply = total_supply + s;
+            } else if s < T::zero() {
+                net.add_arc(index[v], net.target, -s);
+                total_demand = total_demand - s;
+            }
+        }
+        if total_supply != total_demand {
+            return Err(Infeasible);
+        }
+        let value = net.successive_shortest_paths(&arc_costs(self, &cost, &net));
+        if value != total_supply {
+            return Err(Infeasible);
+        }
+        Ok(net.into_cost_flow(self, &cost, value))
+    }
+
+    /// Computes a maximum flow from `source` to `target` with minimum cost using the successive
+    /// shortest paths algorithm with the (non negative) edge capacities `cap` and the (non
+    /// negative) edge costs `cost`.
+    ///
+    /// The running time is `O(F m log n)`, where `F` is the value of the maximum flow.
+    ///
+    /// # Panics
+    ///
+    /// If `source == target`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate fera_graph;
+    ///
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::MinCostFlow;
+    ///
+    /// # fn main() {
+    /// let (g, cap): (StaticDigraph, _) = graph!(
+    ///     4,
+    ///     (0, 1) -> 2i32,
+    ///     (0, 2) -> 2,
+    ///     (1, 3) -> 1,
+    ///     (2, 3) -> 2
+    /// );
+    /// let v: Vec<_> = g.vertices().collect();
+    /// let mut cost = g.default_edge_prop(1);
+    /// cost[g.edge_by_ends(v[0], v[1])] = 5;
+    /// let flow = g.min_cost_max_flow(v[0], v[3], &cap, &cost);
+    /// assert_eq!(3, flow.value);
+    /// assert_eq!(10, flow.cost);
+    /// # }
+    /// ```
+    fn min_cost_max_flow<C, W, T>(
+        &self,
+        source: Vertex<Self>,
+        target: Vertex<Self>,
+        cap: C,
+        cost: W,
+    ) -> CostFlow<Self, T>
+    where
+        Self: IncidenceDigraph + WithEdgeProp<T>,
+        C: EdgePropGet<Self, T>,
+        W: EdgePropGet<Self, T>,
+        T: Copy + Ord + Zero + Bounded + Sub<Output = T> + Mul<Output = T> + Neg<Output = T>,
+    {
+        let mut net = Network::new(self, source, target, &cap);
+        let value = net.successive_shortest_paths(&arc_costs(self, &cost, &net));
+        net.into_cost_flow(self, &cost, value)
+    }
+}
+
+impl<G: Incidence> MinCostFlow for G {}
+
+/// The result of a minimum cost flow algorithm.
+pub struct CostFlow<G: WithEdgeProp<T>, T> {
+    /// The value of the flow, that is, the amount of flow sent from the sources to the sinks.
+    pub value: T,
+    /// The total cost of the flow.
+    pub cost: T,
+    /// The amount of flow in each edge.
+    pub flow: DefaultEdgePropMut<G, T>,
+}
+
+/// The error returned when the supplies and demands cannot be satisfied.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Infeasible;
+
+// Returns the cost of each arc of net, the arcs that are not created from the edges of g have cost
+// zero.
+fn arc_costs<G, W, T>(g: &G, cost: &W, net: &Network<T>) -> Vec<T>
+where
+    G: IncidenceDigraph,
+    W: EdgePropGet<G, T>,
+    T: Copy + Zero + Neg<Output = T>,
+{
+    let mut costs = Vec::with_capacity(net.head.len());
+    for e in g.edges() {
+        let c = cost.get(e);
+        costs.push(c);
+        costs.push(-c);
+    }
+    costs.resize(net.head.len(), T::zero());
+    costs
+}
+
+// A residual network where the vertices are numbered from 0 to n - 1 and the arcs 2 * i and 2 * i
+// + 1 are the forward and backward arcs of the i-th edge. Extra vertices and arcs can be added
+// after the graph ones.
+struct Network<T> {
+    source: usize,
+    target: usize,
+    adj: Vec<Vec<usize>>,
+    head: Vec<usize>,
+    res: Vec<T>,
+}
+
+impl<T> Network<T>
+where
+    T: Copy + Ord + Zero + Sub<Output = T>,
+{
+    fn new<G, C>(g: &G, source: Vertex<G>, target: Vertex<G>, cap: &C) -> Self
+    where
+        G: IncidenceDigraph,
+        C: EdgePropGet<G, T>,
+    {
+        assert!(source != target, "source and target must be different");
+        let (mut net, index) = Network::from_edges(g, cap);
+        net.source = index[source];
+        net.target = index[target];
+        net
+    }
+
+    // Creates a network with the vertices and edges of g, the source and target are not set.
+    fn from_edges<G, C>(g: &G, cap: &C) -> (Self, DefaultVertexPropMut<G, usize>)
+    where
+        G: IncidenceDigraph,
+        C: EdgePropGet<G, T>,
+    {
+        let index = vertex_index(g, g.vertices());
+        let mut net = Network {
+            source: 0,
+            target: 0,
+            adj: vec![vec![]; g.num_vertices()],
+            head: Vec::with_capacity(2 * g.num_edges()),
+            res: Vec::with_capacity(2 * g.num_edges()),
+        };
+        for (e, u, v) in g.edges_with_ends() {
+            let (u, v) = (index[u], index[v]);
+            // a loop is useless, so it is kept saturated
+            net.add_arc(u, v, if u == v { T::zero() } else { cap.get(e) });
+        }
+        (net, index)
+    }
+
+    fn add_vertex(&mut self) -> usize {
+        self.adj.push(vec![]);
+        self.adj.len() - 1
+    }
+
+    fn add_arc(&mut self, u: usize, v: usize, cap: T) {
+        self.adj[u].push(self.head.len());
+        self.head.push(v);
+        self.res.push(cap);
+        self.adj[v].push(self.head.len());
+        self.head.push(u);
+        self.res.push(T::zero());
+    }
+
+    fn push(&mut self, a: usize, value: T) {
+        self.res[a] = self.res[a] - value;
+        self.res[a ^ 1] = self.res[a ^ 1] + value;
+    }
+
+    // Computes the distance (in number of arcs) from the source to each vertex in the residual
+    // network. Unreachable vertices have distance usize::max_value().
+    fn levels(&self) -> Vec<usize> {
+        let mut level = vec![usize::max_value(); self.adj.len()];
+        let mut queue = VecDeque::new();
+        level[self.source] = 0;
+        queue.push_back(self.source);
+        while let Some(u) = queue.pop_front() {
+            for &a in &self.adj[u] {
+                let v = self.head[a];
+                if self.res[a] > T::zero() && level[v] == usize::max_value() {
+                    level[v] = level[u] + 1;
+                    queue.push_back(v);
+                }
+            }
+        }
+        level
+    }
+
+    fn dinic(&mut self) {
+        loop {
+            let mut level = self.levels();
+            if level[self.target] == usize::max_value() {
+                break;
+            }
+            // finds a blocking flow in the level graph, next[u] is the first arc of u that can
+            // still be part of an augmenting path
+            let mut next = vec![0; self.adj.len()];
+            let mut path: Vec<usize> = vec![];
+            let mut u = self.source;
+            loop {
+                if u == self.target {
+                    let value = path.iter().map(|&a| self.res[a]).min().unwrap();
+                    for &a in &path {
+                        self.push(a, value);
+                    }
+                    // restarts from the tail of the first saturated arc
+                    let i = path.iter().position(|&a| self.res[a] == T::zero()).unwrap();
+                    u = self.head[path[i] ^ 1];
+                    path.truncate(i);
+                    continue;
+                }
+                while next[u] < self.adj[u].len() {
+                    let a = self.adj[u][next[u]];
+                    if self.res[a] > T::zero() && level[self.head[a]] == level[u] + 1 {
+                        break;
+                    }
+                    next[u] += 1;
+                }
+                if next[u] < self.adj[u].len() {
+                    let a = self.adj[u][next[u]];
+                    path.push(a);
+                    u = self.head[a];
+                } else if let Some(a) = path.pop() {
+                    // u is a dead end
+                    level[u] = usize::max_value();
+                    u = self.head[a ^ 1];
+                    next[u] += 1;
+                } else {
+                    break;
+                }
+            }
+        }
+    }
+
+    fn push_relabel(&mut self) {
+        let n = self.adj.len();
+        let (s, t) = (self.source, self.target);
+        let mut height = vec![0; n];
+        let mut excess = vec![T::zero(); n];
+        let mut next = vec![0; n];
+        let mut queue = VecDeque::new();
+        height[s] = n;
+        for i in 0..self.adj[s].len() {
+            let a = self.adj[s][i];
+            let (v, value) = (self.head[a], self.res[a]);
+            if value > T::zero() {
+                self.push(a, value);
+                excess[v] = excess[v] + value;
+                if v != t && excess[v] == value {
+                    queue.push_back(v);
+                }
+            }
+        }
+        while let Some(u) = queue.pop_front() {
+            // discharges u
+            while excess[u] > T::zero() {
+                if next[u] == self.adj[u].len() {
+                    // relabel
+                    height[u] = self.adj[u]
+                        .iter()
+                        .filter(|&&a| self.res[a] > T::zero())
+                        .map(|&a| height[self.head[a]] + 1)
+                        .min()
+                        .unwrap();
+                    next[u] = 0;
+                    continue;
+                }
+                let a = self.adj[u][next[u]];
+                let v = self.head[a];
+                if self.res[a] > T::zero() && height[u] == height[v] + 1 {
+                    let value = min(excess[u], self.res[a]);
+                    self.push(a, value);
+                    excess[u] = excess[u] - value;
+                    if v != s && v != t && excess[v] == T::zero() {
+                        queue.push_back(v);
+                    }
+                    excess[v] = excess[v] + value;
+                } else {
+                    next[u] += 1;
+                }
+            }
+        }
+    }
+
+    fn into_flow<G>(self, g: &G) -> Flow<G, T>
+    where
+        G: IncidenceDigraph + WithEdgeProp<T>,
+    {
+        let level = self.levels();
+        let source_side = g
+            .vertices()
+            .zip(&level)
+            .filter(|&(_, &l)| l != usize::max_value())
+            .map(|(v, _)| v)
+            .collect();
+        let mut flow = g.default_edge_prop(T::zero());
+        let (mut out, mut in_) = (T::zero(), T::zero());
+        for (i, e) in g.edges().enumerate() {
+            let f = self.res[2 * i + 1];
+            flow[e] = f;
+            // the tail of the backward arc is the head of the edge
+            if self.head[2 * i + 1] == self.source {
+                out = out + f;
+            }
+            if self.head[2 * i] == self.source {
+                in_ = in_ + f;
+            }
+        }
+        let value = out - in_;
+        Flow {
+            value,
+            flow,
+            source_side,
+        }
+    }
+}
+
+impl<T> Network<T>
+where
+    T: Copy + Ord + Zero + Bounded + Sub<Output = T> + Mul<Output = T>,
+{
+    // Sends flow from the source to the target along shortest paths with respect to cost until
+    // there is no augmenting path. The potentials keep the reduced costs of the residual arcs non
+    // negative, so Dijkstra's algorithm can be used. Returns the value of the flow.
+    fn successive_shortest_paths(&mut self, cost: &[T]) -> T {
+        let n = self.adj.len();
+        let (s, t) = (self.source, self.target);
+        let mut pi = vec![T::zero(); n];
+        let mut value = T::zero();
+        loop {
+            let mut dist = vec![T::max_value(); n];
+            let mut parent = vec![usize::max_value(); n];
+            let mut queue = BinaryHeap::new();
+            dist[s] = T::zero();
+            queue.push(QueueItem::new(T::zero(), s));
+            while let Some(QueueItem { prio: d, item: u }) = queue.pop() {
+                if d > dist[u] {
+                    continue;
+                }
+                for &a in &self.adj[u] {
+                    let v = self.head[a];
+                    if self.res[a] == T::zero() {
+                        continue;
+                    }
+                    let dv = d + cost[a] + pi[u] - pi[v];
+                    if dv < dist[v] {
+                        dist[v] = dv;
+                        parent[v] = a;
+                        queue.push(QueueItem::new(dv, v));
+                    }
+                }
+            }
+            if dist[t] == T::max_value() {
+                break;
+            }
+            for v in 0..n {
+                pi[v] = pi[v] + min(dist[v], dist[t]);
+            }
+            let mut bottleneck = self.res[parent[t]];
+            let mut v = self.head[parent[t] ^ 1];
+            while v != s {
+                bottleneck = min(bottleneck, self.res[parent[v]]);
+                v = self.head[parent[v] ^ 1];
+            }
+            let mut v = t;
+            while v != s {
+                let a = parent[v];
+                self.push(a, bottleneck);
+                v = self.head[a ^ 1];
+            }
+            value = value + bottleneck;
+        }
+        value
+    }
+
+    fn into_cost_flow<G, W>(self, g: &G, cost: &W, value: T) -> CostFlow<G, T>
+    where
+        G: IncidenceDigraph + WithEdgeProp<T>,
+        W: EdgePropGet<G, T>,
+    {
+        let mut flow = g.default_edge_prop(T::zero());
+        let mut total = T::zero();
+        for (i, e) in g.edges().enumerate() {
+            let f = self.res[2 * i + 1];
+            flow[e] = f;
+            total = total + f * cost.get(e);
+        }
+        CostFlow {
+            value,
+            cost: total,
+            flow,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fera_fun::vec;
+
+    fn graph() -> (StaticDigraph, DefaultEdgePropMut<StaticDigraph, u32>) {
+        // Example from CLRS
+        graph!(
+            6,
+            (0, 1) -> 16,
+            (0, 2) -> 13,
+            (1, 3) -> 12,
+            (2, 1) -> 4,
+            (2, 4) -> 14,
+            (3, 2) -> 9,
+            (3, 5) -> 20,
+            (4, 3) -> 7,
+            (4, 5) -> 4
+        )
+    }
+
+    fn check(
+        g: &StaticDigraph,
+        cap: &DefaultEdgePropMut<StaticDigraph, u32>,
+        flow: &Flow<StaticDigraph, u32>,
+    ) {
+        let v = vec(g.vertices());
+        assert_eq!(23, flow.value);
+        assert_eq!(vec![v[0], v[1], v[2], v[4]], flow.source_side);
+        for e in g.edges() {
+            assert!(flow.flow[e] <= cap[e]);
+        }
+        for &u in &v[1..5] {
+            let out: u32 = g.out_edges(u).map(|e| flow.flow[e]).sum();
+            let in_: u32 = g
+                .edges()
+                .filter(|&e| g.target(e) == u)
+                .map(|e| flow.flow[e])
+                .sum();
+            assert_eq!(in_, out);
+        }
+    }
+
+    #[test]
+    fn dinic() {
+        let (g, cap) = graph();
+        let v = vec(g.vertices());
+        check(&g, &cap, &g.dinic(v[0], v[5], &cap));
+    }
+
+    #[test]
+    fn push_relabel() {
+        let (g, cap) = graph();
+        let v = vec(g.vertices());
+        check(&g, &cap, &g.push_relabel(v[0], v[5], &cap));
+    }
+
+    #[test]
+    fn min_cost_flow() {
+        // a transportation problem with two factories (0 and 1) and three stores (2, 3 and 4)
+        let (g, cost): (StaticDigraph, _) = graph!(
+            5,
+            (0, 2) -> 4i64,
+            (0, 3) -> 6,
+            (0, 4) -> 9,
+            (1, 2) -> 5,
+            (1, 3) -> 3,
+            (1, 4) -> 8
+        );
+        let v = vec(g.vertices());
+        let cap = g.default_edge_prop(100);
+        let mut supply = g.default_vertex_prop(0);
+        supply[v[0]] = 40;
+        supply[v[1]] = 30;
+        supply[v[2]] = -20;
+        supply[v[3]] = -25;
+        supply[v[4]] = -25;
+        let flow = g.min_cost_flow(&cap, &cost, &supply).unwrap();
+        assert_eq!(70, flow.value);
+        assert_eq!(20 * 4 + 20 * 9 + 25 * 3 + 5 * 8, flow.cost);
+        assert_eq!(20, flow.flow[g.edge_by_ends(v[0], v[2])]);
+        assert_eq!(25, flow.flow[g.edge_by_ends(v[1], v[3])]);
+
+        // the capacities are not enough
+        let cap = g.default_edge_prop(10);
+        assert!(g.min_cost_flow(&cap, &cost, &supply).is_err());
+
+        // the supplies and demands are not balanced
+        supply[v[4]] = -20;
+        assert!(g
+            .min_cost_flow(&g.default_edge_prop(100), &cost, &supply)
+            .is_err());
+    }
+
+    #[test]
+    fn min_cost_max_flow() {
+        let (g, cap) = graph();
+        let cap = cap.map(i64::from);
+        let v = vec(g.vertices());
+        let cost = g.default_edge_prop(1i64);
+        let flow = g.min_cost_max_flow(v[0], v[5], &cap, &cost);
+        assert_eq!(23, flow.value);
+        assert_eq!(flow.cost, g.edges().map(|e| flow.flow[e]).sum::<i64>());
+        // the 7 units that cross (4, 3) use a path with 4 edges, the others use paths with 3 edges
+        assert_eq!(16 * 3 + 7 * 4, flow.cost);
+    }
+}
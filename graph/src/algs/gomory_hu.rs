@@ -0,0 +1,225 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! [Gomory-Hu tree] of undirected graphs.
+//!
+//! A Gomory-Hu tree represents the minimum cuts between all pairs of vertices of a graph: the
+//! weight of a minimum cut between `u` and `v` is the weight of the lightest edge in the path
+//! between `u` and `v` in the tree, and removing this edge from the tree separates the vertices
+//! in the two sides of the cut.
+//!
+//! [Gomory-Hu tree]: https://en.wikipedia.org/wiki/Gomory–Hu_tree
+
+use algs::index::vertex_index;
+use algs::MaxFlow;
+use prelude::*;
+
+use num_traits::Zero;
+
+use std::ops::Sub;
+
+pub trait GomoryHu: Incidence {
+    /// Constructs a Gomory-Hu tree with the (non negative) edge weights `w` using the Gusfield's
+    /// algorithm.
+    ///
+    /// The algorithm computes `n - 1` maximum flows with [`MaxFlow::dinic`], so the running time
+    /// is `O(n^3 m)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate fera_graph;
+    ///
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::GomoryHu;
+    ///
+    /// # fn main() {
+    /// // two triangles connected by two light edges
+    /// let (g, w): (StaticGraph, _) = graph!(
+    ///     6,
+    ///     (0, 1) -> 3u32,
+    ///     (1, 2) -> 3,
+    ///     (2, 0) -> 3,
+    ///     (3, 4) -> 3,
+    ///     (4, 5) -> 3,
+    ///     (5, 3) -> 3,
+    ///     (0, 3) -> 1,
+    ///     (2, 5) -> 2
+    /// );
+    /// let v: Vec<_> = g.vertices().collect();
+    /// let gh = g.gomory_hu_tree(&w);
+    /// assert_eq!(5, gh.tree.num_edges());
+    /// assert_eq!(3, gh.min_cut(v[1], v[4]));
+    /// assert_eq!(6, gh.min_cut(v[0], v[1]));
+    /// assert_eq!(7, gh.min_cut(v[0], v[2]));
+    /// # }
+    /// ```
+    ///
+    /// [`MaxFlow::dinic`]: ../flow/trait.MaxFlow.html#method.dinic
+    fn gomory_hu_tree<W, T>(&self, w: W) -> GomoryHuTree<Self, T>
+    where
+        Self: Graph,
+        W: EdgePropGet<Self, T>,
+        T: Copy + Ord + Zero + Sub<Output = T>,
+    {
+        let vertices: Vec<_> = self.vertices().collect();
+        let n = vertices.len();
+        let index = vertex_index(self, &vertices);
+        let mut arcs = vec![];
+        let mut caps = vec![];
+        for (e, u, v) in self.edges_with_ends() {
+            arcs.push((index[u], index[v]));
+            arcs.push((index[v], index[u]));
+            caps.push(w.get(e));
+            caps.push(w.get(e));
+        }
+        let net = StaticDigraph::new_with_edges(n, arcs);
+        let mut cap = net.default_edge_prop(T::zero());
+        for (e, &c) in net.edges().zip(&caps) {
+            cap[e] = c;
+        }
+
+        // the tree edges are (s, parent[s]) with weight cut[s] for s > 0
+        let mut parent = vec![0; n];
+        let mut cut = vec![T::zero(); n];
+        for s in 1..n {
+            let t = parent[s];
+            let flow = net.dinic(s as u32, t as u32, &cap);
+            let mut side = vec![false; n];
+            for v in flow.source_side {
+                side[v as usize] = true;
+            }
+            cut[s] = flow.value;
+            for (i, p) in parent.iter_mut().enumerate() {
+                if i != s && side[i] && *p == t {
+                    *p = s;
+                }
+            }
+            if side[parent[t]] {
+                parent[s] = parent[t];
+                parent[t] = s;
+                cut[s] = cut[t];
+                cut[t] = flow.value;
+            }
+        }
+
+        let mut depth = vec![usize::max_value(); n];
+        if n > 0 {
+            depth[0] = 0;
+        }
+        for s in 0..n {
+            let mut path = vec![];
+            let mut v = s;
+            while depth[v] == usize::max_value() {
+                path.push(v);
+                v = parent[v];
+            }
+            while let Some(u) = path.pop() {
+                depth[u] = depth[parent[u]] + 1;
+            }
+        }
+
+        let tree = StaticGraph::new_with_edges(n, (1..n).map(|s| (s, parent[s])));
+        let mut weight = tree.default_edge_prop(T::zero());
+        for (e, u, v) in tree.edges_with_ends() {
+            let (u, v) = (u as usize, v as usize);
+            weight[e] = if parent[u] == v { cut[u] } else { cut[v] };
+        }
+        GomoryHuTree {
+            tree,
+            weight,
+            vertices,
+            index,
+            parent,
+            depth,
+            cut,
+        }
+    }
+}
+
+impl<G: Incidence> GomoryHu for G {}
+
+/// A Gomory-Hu tree created by [`GomoryHu::gomory_hu_tree`].
+///
+/// [`GomoryHu::gomory_hu_tree`]: trait.GomoryHu.html#method.gomory_hu_tree
+pub struct GomoryHuTree<G: WithVertexProp<usize>, T> {
+    /// The tree, the vertex `i` of the tree corresponds to the vertex `vertices[i]` of the graph.
+    pub tree: StaticGraph,
+    /// The weight of each edge of the tree, that is, the weight of a minimum cut between its
+    /// ends.
+    pub weight: DefaultEdgePropMut<StaticGraph, T>,
+    /// The vertices of the graph.
+    pub vertices: Vec<Vertex<G>>,
+    index: DefaultVertexPropMut<G, usize>,
+    parent: Vec<usize>,
+    depth: Vec<usize>,
+    cut: Vec<T>,
+}
+
+impl<G: WithVertexProp<usize>, T: Copy + Ord> GomoryHuTree<G, T> {
+    /// Returns the weight of a minimum cut between `u` and `v` in the graph, that is, the weight
+    /// of the lightest edge in the path between `u` and `v` in the tree.
+    ///
+    /// The running time is proportional to the length of the path.
+    ///
+    /// # Panics
+    ///
+    /// If `u == v`.
+    pub fn min_cut(&self, u: Vertex<G>, v: Vertex<G>) -> T {
+        let (mut u, mut v) = (self.index[u], self.index[v]);
+        assert!(u != v, "u and v must be different");
+        let mut min = None;
+        while u != v {
+            if self.depth[u] < self.depth[v] {
+                ::std::mem::swap(&mut u, &mut v);
+            }
+            min = Some(match min {
+                Some(m) if m <= self.cut[u] => m,
+                _ => self.cut[u],
+            });
+            u = self.parent[u];
+        }
+        min.unwrap()
+    }
+
+    /// Returns the vertex of the tree that corresponds to the vertex `v` of the graph.
+    pub fn tree_vertex(&self, v: Vertex<G>) -> Vertex<StaticGraph> {
+        self.index[v] as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fun::sum_prop;
+
+    #[test]
+    fn gomory_hu_tree() {
+        // the vertex 4 is isolated, 1 and 2 are connected by parallel edges
+        let (g, w): (StaticGraph, _) = graph!(
+            5,
+            (0, 1) -> 2u32,
+            (1, 2) -> 3,
+            (1, 2) -> 2,
+            (2, 3) -> 1,
+            (3, 3) -> 7
+        );
+        let v: Vec<_> = g.vertices().collect();
+        let gh = g.gomory_hu_tree(&w);
+        assert_eq!(4, gh.tree.num_edges());
+        assert_eq!(2u32 + 5 + 1, sum_prop(&gh.weight, gh.tree.edges()));
+        assert_eq!(2, gh.min_cut(v[0], v[1]));
+        assert_eq!(5, gh.min_cut(v[2], v[1]));
+        assert_eq!(1, gh.min_cut(v[0], v[3]));
+        assert_eq!(0, gh.min_cut(v[4], v[2]));
+        assert_eq!(4, gh.tree_vertex(v[4]));
+
+        let g: StaticGraph = graph!(1);
+        assert_eq!(
+            0,
+            g.gomory_hu_tree(g.default_edge_prop(1u32)).tree.num_edges()
+        );
+    }
+}
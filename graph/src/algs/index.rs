@@ -0,0 +1,21 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use params::IntoOwned;
+use prelude::*;
+
+// Returns a vertex property that maps each vertex of `vertices` to its position in `vertices`.
+// The value of the vertices not in `vertices` is 0.
+pub(crate) fn vertex_index<G, I>(g: &G, vertices: I) -> DefaultVertexPropMut<G, usize>
+where
+    G: WithVertexProp<usize>,
+    I: IntoIterator,
+    I::Item: IntoOwned<Vertex<G>>,
+{
+    let mut index = g.default_vertex_prop(0usize);
+    for (i, v) in vertices.into_iter().enumerate() {
+        index[v.into_owned()] = i;
+    }
+    index
+}
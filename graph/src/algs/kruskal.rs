@@ -6,10 +6,13 @@
 //!
 //! [Kruskal]: https://en.wikipedia.org/wiki/Kruskal's_algorithm
 
+use algs::mst;
 use params::*;
 use prelude::*;
 use unionfind::{NewUnionFind, UnionFind, WithUnionFind};
 
+pub use algs::mst::AcceptAll;
+
 use fera_fun::vec;
 
 use std::ops::DerefMut;
@@ -24,9 +27,6 @@ where
     fn after_union(&mut self, g: &G, e: Edge<G>, ds: &mut UnionFind<G>) {}
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-pub struct AcceptAll;
-
 impl<G> Visitor<G> for AcceptAll
 where
     G: WithEdge + WithUnionFind,
@@ -46,6 +46,19 @@ where
     }
 }
 
+/// Adapts a [`mst::Visitor`](../mst/trait.Visitor.html) to be used as a Kruskal's `Visitor`.
+pub struct MstVisitor<V>(pub V);
+
+impl<G, V> Visitor<G> for MstVisitor<V>
+where
+    G: WithEdge + WithUnionFind,
+    V: mst::Visitor<G>,
+{
+    fn accept(&mut self, g: &G, e: Edge<G>, _ds: &mut UnionFind<G>) -> bool {
+        self.0.accept(g, e)
+    }
+}
+
 pub struct Iter<'a, G: 'a, E, V, U> {
     g: &'a G,
     edges: E,
@@ -114,6 +127,15 @@ where
         let edges = vec(self.0.edges()).sorted_by_prop(&w);
         self.edges(edges)
     }
+
+    /// Sets a [`mst::Visitor`](../mst/trait.Visitor.html) as the visitor, so the same visitor can
+    /// be used with all minimum spanning tree algorithms.
+    pub fn mst_visitor<M>(self, visitor: M) -> KruskalAlg<&'a G, E, MstVisitor<M>, U>
+    where
+        M: mst::Visitor<G>,
+    {
+        self.visitor(MstVisitor(visitor))
+    }
 }
 
 impl<'a, G, E, V, U> IntoIterator for KruskalAlg<&'a G, E, V, U>
@@ -142,8 +164,8 @@ where
 mod tests {
     use super::Kruskal;
     use fera_fun::vec;
-    use prelude::*;
     use fun::sum_prop;
+    use prelude::*;
 
     #[test]
     fn kruskal_mst() {
@@ -0,0 +1,1473 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Matching algorithms, including [Hopcroft-Karp] for bipartite graphs, [Edmonds]' blossom
+//! algorithm for general graphs (for maximum cardinality and for weighted matchings) and the
+//! [Hungarian] algorithm for the assignment problem.
+//!
+//! [Hopcroft-Karp]: https://en.wikipedia.org/wiki/Hopcroft–Karp_algorithm
+//! [Edmonds]: https://en.wikipedia.org/wiki/Blossom_algorithm
+//! [Hungarian]: https://en.wikipedia.org/wiki/Hungarian_algorithm
+
+use algs::index::vertex_index;
+use algs::Bipartite;
+use prelude::*;
+use props::FnProp;
+
+use num_traits::{Bounded, One, Zero};
+
+use std::collections::VecDeque;
+use std::ops::{Div, Neg, Sub};
+
+const NONE: usize = usize::max_value();
+
+pub trait Matching: Incidence {
+    /// Finds a maximum matching in a bipartite graph using the Hopcroft-Karp algorithm. The
+    /// vertices `v` with `side[v] == false` form one part of the bipartition and the others form
+    /// the other part. The edges with both ends in the same part are ignored.
+    ///
+    /// Returns the edges of the matching and a vertex property that maps each matched vertex to
+    /// its mate.
+    ///
+    /// The running time is `O(m sqrt(n))`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate fera_graph;
+    ///
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::Matching;
+    /// use fera_graph::props::FnProp;
+    ///
+    /// # fn main() {
+    /// let g: StaticGraph = graph!(6, (0, 3), (0, 4), (1, 3), (2, 4), (2, 5));
+    /// let v: Vec<_> = g.vertices().collect();
+    /// let (matching, mate) = g.hopcroft_karp(FnProp(|v| v >= 3));
+    /// assert_eq!(3, matching.len());
+    /// assert_eq!(Some(v[3]), mate[v[1]].into_option());
+    /// assert_eq!(Some(v[1]), mate[v[3]].into_option());
+    /// # }
+    /// ```
+    fn hopcroft_karp<S>(
+        &self,
+        side: S,
+    ) -> (
+        Vec<Edge<Self>>,
+        DefaultVertexPropMut<Self, OptionVertex<Self>>,
+    )
+    where
+        Self: IncidenceGraph,
+        S: VertexPropGet<Self, bool>,
+    {
+        let mut net = Net::new(self, |u, v| side.get(u) != side.get(v));
+        let left = self
+            .vertices()
+            .enumerate()
+            .filter(|&(_, v)| !side.get(v))
+            .map(|(i, _)| i)
+            .collect();
+        net.hopcroft_karp(left);
+        net.into_matching(self)
+    }
+
+    /// Finds a maximum matching using the Hopcroft-Karp algorithm if the graph is bipartite. The
+    /// bipartition is detected by a breadth first search. Returns `None` if the graph is not
+    /// bipartite.
+    ///
+    /// See [`hopcroft_karp`](#method.hopcroft_karp).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate fera_graph;
+    ///
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::Matching;
+    ///
+    /// # fn main() {
+    /// let g: StaticGraph = graph!(4, (0, 1), (1, 2), (2, 3));
+    /// let (matching, _) = g.bipartite_matching().unwrap();
+    /// assert_eq!(2, matching.len());
+    ///
+    /// let g: StaticGraph = graph!(3, (0, 1), (1, 2), (2, 0));
+    /// assert!(g.bipartite_matching().is_none());
+    /// # }
+    /// ```
+    fn bipartite_matching(
+        &self,
+    ) -> Option<(
+        Vec<Edge<Self>>,
+        DefaultVertexPropMut<Self, OptionVertex<Self>>,
+    )>
+    where
+        Self: IncidenceGraph,
+    {
+        let side = self.bipartition().ok()?;
+        Some(self.hopcroft_karp(FnProp(|v| side[v])))
+    }
+
+    /// Finds a maximum matching in a general graph using the Edmonds' blossom algorithm.
+    ///
+    /// Returns the edges of the matching and a vertex property that maps each matched vertex to
+    /// its mate.
+    ///
+    /// The running time is `O(n^3)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate fera_graph;
+    ///
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::Matching;
+    ///
+    /// # fn main() {
+    /// // a triangle with a pendant path
+    /// let g: StaticGraph = graph!(5, (0, 1), (1, 2), (2, 0), (2, 3), (3, 4));
+    /// let (matching, mate) = g.edmonds();
+    /// assert_eq!(2, matching.len());
+    /// assert!(g.vertices().all(|v| match mate[v].into_option() {
+    ///     Some(u) => mate[u].into_option() == Some(v),
+    ///     None => true,
+    /// }));
+    /// # }
+    /// ```
+    fn edmonds(
+        &self,
+    ) -> (
+        Vec<Edge<Self>>,
+        DefaultVertexPropMut<Self, OptionVertex<Self>>,
+    )
+    where
+        Self: IncidenceGraph,
+    {
+        let mut net = Net::new(self, |u, v| u != v);
+        net.edmonds();
+        net.into_matching(self)
+    }
+
+    /// Finds a minimum cost perfect matching in a bipartite graph using the Hungarian algorithm
+    /// with the edge weights `w`. The vertices `v` with `side[v] == false` form one part of the
+    /// bipartition and the others form the other part. The edges with both ends in the same part
+    /// are ignored. Returns `None` if the graph has no perfect matching.
+    ///
+    /// The running time is `O(n^3)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate fera_graph;
+    ///
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::Matching;
+    /// use fera_graph::props::FnProp;
+    ///
+    /// # fn main() {
+    /// let (g, w): (StaticGraph, _) = graph!(
+    ///     4,
+    ///     (0, 2) -> 1i32,
+    ///     (0, 3) -> 2,
+    ///     (1, 2) -> 3,
+    ///     (1, 3) -> 5
+    /// );
+    /// let v: Vec<_> = g.vertices().collect();
+    /// let a = g.hungarian(FnProp(|v| v >= 2), &w).unwrap();
+    /// assert_eq!(5, a.cost);
+    /// assert_eq!(Some(v[3]), a.mate[v[0]].into_option());
+    /// // the potentials are a dual solution with the same value
+    /// assert_eq!(a.cost, g.vertices().map(|v| a.potential[v]).sum::<i32>());
+    /// # }
+    /// ```
+    fn hungarian<S, W, T>(&self, side: S, w: W) -> Option<Assignment<Self, T>>
+    where
+        Self: IncidenceGraph + WithVertexProp<T>,
+        S: VertexPropGet<Self, bool>,
+        W: EdgePropGet<Self, T>,
+        T: Copy + Ord + Zero + Bounded + Sub<Output = T> + Neg<Output = T>,
+    {
+        let rows: Vec<_> = self.vertices().filter(|&v| !side.get(v)).collect();
+        let cols: Vec<_> = self.vertices().filter(|&v| side.get(v)).collect();
+        if rows.len() != cols.len() {
+            return None;
+        }
+        let n = rows.len();
+        // the rows are indexed from 0 and the columns from n
+        let index = vertex_index(self, rows.iter().chain(&cols));
+        // the cheapest edge between each pair of vertices
+        let mut best = vec![vec![None; n]; n];
+        for (e, u, v) in self.edges_with_ends() {
+            if side.get(u) == side.get(v) {
+                continue;
+            }
+            let (i, j) = if side.get(u) {
+                (index[v], index[u] - n)
+            } else {
+                (index[u], index[v] - n)
+            };
+            let x = w.get(e);
+            match best[i][j] {
+                Some((y, _)) if y <= x => (),
+                _ => best[i][j] = Some((x, e)),
+            }
+        }
+        let a = kuhn_munkres(n, n, |i, j| best[i][j].map(|(x, _)| x))?;
+        let mut mate = self.default_vertex_prop(Self::vertex_none());
+        let mut potential = self.default_vertex_prop(T::zero());
+        let mut edges = Vec::with_capacity(n);
+        for (i, &j) in a.assignment.iter().enumerate() {
+            let (u, v) = (rows[i], cols[j]);
+            edges.push(best[i][j].unwrap().1);
+            mate[u] = Self::vertex_some(v);
+            mate[v] = Self::vertex_some(u);
+            potential[u] = a.row_potential[i];
+            potential[v] = a.col_potential[j];
+        }
+        Some(Assignment {
+            edges,
+            mate,
+            cost: a.cost,
+            potential,
+        })
+    }
+
+    /// Finds a maximum weight matching in a general graph using the Edmonds' blossom algorithm
+    /// with the edge weights `w`. The edges with negative weight are never used.
+    ///
+    /// Returns the edges of the matching and a vertex property that maps each matched vertex to
+    /// its mate.
+    ///
+    /// The running time is `O(n^3)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate fera_graph;
+    ///
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::Matching;
+    /// use fera_graph::sum_prop;
+    ///
+    /// # fn main() {
+    /// // the heaviest edge is in the middle of a path
+    /// let (g, w): (StaticGraph, _) = graph!(
+    ///     4,
+    ///     (0, 1) -> 3i32,
+    ///     (1, 2) -> 5,
+    ///     (2, 3) -> 3
+    /// );
+    /// let (matching, _) = g.max_weight_matching(&w);
+    /// assert_eq!(6, sum_prop(&w, &matching));
+    /// # }
+    /// ```
+    fn max_weight_matching<W, T>(
+        &self,
+        w: W,
+    ) -> (
+        Vec<Edge<Self>>,
+        DefaultVertexPropMut<Self, OptionVertex<Self>>,
+    )
+    where
+        Self: IncidenceGraph,
+        W: EdgePropGet<Self, T>,
+        T: Copy + Ord + Zero + One + Sub<Output = T> + Div<Output = T>,
+    {
+        let (edges, ends) = weighted_edges(self, |e| w.get(e));
+        let mate = weighted_matching(self.num_vertices(), &ends, false);
+        into_weighted_matching(self, &edges, &ends, &mate)
+    }
+
+    /// Finds a minimum weight perfect matching in a general graph using the Edmonds' blossom
+    /// algorithm with the edge weights `w`. Returns `None` if the graph has no perfect matching.
+    ///
+    /// Returns the edges of the matching and a vertex property that maps each vertex to its mate.
+    ///
+    /// The running time is `O(n^3)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate fera_graph;
+    ///
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::Matching;
+    /// use fera_graph::sum_prop;
+    ///
+    /// # fn main() {
+    /// let (g, w): (StaticGraph, _) = graph!(
+    ///     4,
+    ///     (0, 1) -> 1u32,
+    ///     (1, 2) -> 1,
+    ///     (2, 3) -> 1,
+    ///     (3, 0) -> 3,
+    ///     (0, 2) -> 2
+    /// );
+    /// let (matching, _) = g.min_weight_perfect_matching(&w).unwrap();
+    /// assert_eq!(2u32, sum_prop(&w, &matching));
+    ///
+    /// let g: StaticGraph = graph!(4, (0, 1), (0, 2), (0, 3));
+    /// assert!(g.min_weight_perfect_matching(g.default_edge_prop(1u32)).is_none());
+    /// # }
+    /// ```
+    fn min_weight_perfect_matching<W, T>(
+        &self,
+        w: W,
+    ) -> Option<(
+        Vec<Edge<Self>>,
+        DefaultVertexPropMut<Self, OptionVertex<Self>>,
+    )>
+    where
+        Self: IncidenceGraph,
+        W: EdgePropGet<Self, T>,
+        T: Copy + Ord + Zero + One + Sub<Output = T> + Div<Output = T>,
+    {
+        // maximizing max - w(e) over the maximum cardinality matchings
+        let max = self.edges().map(|e| w.get(e)).max().unwrap_or_else(T::zero);
+        let (edges, ends) = weighted_edges(self, |e| max - w.get(e));
+        let mate = weighted_matching(self.num_vertices(), &ends, true);
+        if mate.contains(&NONE) {
+            return None;
+        }
+        Some(into_weighted_matching(self, &edges, &ends, &mate))
+    }
+}
+
+impl<G: Incidence> Matching for G {}
+
+/// The result of the Hungarian algorithm over a bipartite graph.
+pub struct Assignment<G, T>
+where
+    G: WithEdge + WithVertexProp<T> + WithVertexProp<OptionVertex<G>>,
+{
+    /// The edges of the matching.
+    pub edges: Vec<Edge<G>>,
+    /// The mate of each vertex.
+    pub mate: DefaultVertexPropMut<G, OptionVertex<G>>,
+    /// The cost of the matching.
+    pub cost: T,
+    /// The dual potentials. For each edge `e = (u, v)` that crosses the bipartition
+    /// `potential[u] + potential[v] <= w(e)`, with equality for the edges of the matching.
+    pub potential: DefaultVertexPropMut<G, T>,
+}
+
+/// The result of the Hungarian algorithm over a cost matrix.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MatrixAssignment<T> {
+    /// The column assigned to each row.
+    pub assignment: Vec<usize>,
+    /// The cost of the assignment.
+    pub cost: T,
+    /// The dual potential of each row.
+    pub row_potential: Vec<T>,
+    /// The dual potential of each column. For each row `i` and column `j`, `row_potential[i] +
+    /// col_potential[j] <= cost[i][j]`, with equality if `j` is assigned to `i`.
+    pub col_potential: Vec<T>,
+}
+
+/// Finds an assignment of the rows to distinct columns of the matrix `cost` with minimum total
+/// cost using the Hungarian algorithm.
+///
+/// The running time is `O(n^2 m)`, where `n` is the number of rows and `m` is the number of
+/// columns.
+///
+/// # Panics
+///
+/// If the rows have different lengths or if there are more rows than columns.
+///
+/// # Example
+///
+/// ```
+/// use fera_graph::algs::matching::hungarian;
+///
+/// let cost = vec![vec![4, 1, 3], vec![2, 0, 5], vec![3, 2, 2]];
+/// let a = hungarian(&cost);
+/// assert_eq!(vec![1, 0, 2], a.assignment);
+/// assert_eq!(5, a.cost);
+/// ```
+pub fn hungarian<T>(cost: &[Vec<T>]) -> MatrixAssignment<T>
+where
+    T: Copy + Ord + Zero + Bounded + Sub<Output = T> + Neg<Output = T>,
+{
+    let n = cost.len();
+    let m = cost.first().map_or(0, |row| row.len());
+    assert!(
+        cost.iter().all(|row| row.len() == m),
+        "rows with different lengths"
+    );
+    assert!(n <= m, "more rows than columns");
+    kuhn_munkres(n, m, |i, j| Some(cost[i][j])).unwrap()
+}
+
+// Solves the assignment problem for n rows and m columns where cost(i, j) is the cost of assigning
+// the column j to row i or None if it is not allowed. Returns None if there is no assignment.
+fn kuhn_munkres<T, F>(n: usize, m: usize, cost: F) -> Option<MatrixAssignment<T>>
+where
+    T: Copy + Ord + Zero + Bounded + Sub<Output = T> + Neg<Output = T>,
+    F: Fn(usize, usize) -> Option<T>,
+{
+    // the rows and columns are numbered from 1, the row 0 and column 0 are sentinels
+    let inf = T::max_value();
+    let mut u = vec![T::zero(); n + 1];
+    let mut v = vec![T::zero(); m + 1];
+    // p[j] is the row assigned to the column j
+    let mut p = vec![0; m + 1];
+    let mut way = vec![0; m + 1];
+    for i in 1..=n {
+        // adds the row i by finding a shortest augmenting path
+        p[0] = i;
+        let mut j0 = 0;
+        let mut minv = vec![inf; m + 1];
+        let mut used = vec![false; m + 1];
+        loop {
+            used[j0] = true;
+            let i0 = p[j0];
+            let mut delta = inf;
+            let mut j1 = 0;
+            for j in 1..=m {
+                if used[j] {
+                    continue;
+                }
+                if let Some(c) = cost(i0 - 1, j - 1) {
+                    let cur = c - u[i0] - v[j];
+                    if cur < minv[j] {
+                        minv[j] = cur;
+                        way[j] = j0;
+                    }
+                }
+                if minv[j] < delta {
+                    delta = minv[j];
+                    j1 = j;
+                }
+            }
+            if delta == inf {
+                return None;
+            }
+            for j in 0..=m {
+                if used[j] {
+                    u[p[j]] = u[p[j]] + delta;
+                    v[j] = v[j] - delta;
+                } else if minv[j] != inf {
+                    minv[j] = minv[j] - delta;
+                }
+            }
+            j0 = j1;
+            if p[j0] == 0 {
+                break;
+            }
+        }
+        // flips the augmenting path
+        while j0 != 0 {
+            let j1 = way[j0];
+            p[j0] = p[j1];
+            j0 = j1;
+        }
+    }
+    let mut assignment = vec![0; n];
+    for j in 1..=m {
+        if p[j] != 0 {
+            assignment[p[j] - 1] = j - 1;
+        }
+    }
+    let total = assignment
+        .iter()
+        .enumerate()
+        .fold(T::zero(), |acc, (i, &j)| acc + cost(i, j).unwrap());
+    Some(MatrixAssignment {
+        assignment,
+        cost: total,
+        row_potential: u[1..].to_vec(),
+        col_potential: v[1..].to_vec(),
+    })
+}
+
+// Returns the edges of g that are not loops and the triples (u, v, w(e)) for each of these edges,
+// where u and v are the indices of the ends.
+fn weighted_edges<G, T, F>(g: &G, w: F) -> (Vec<Edge<G>>, Vec<(usize, usize, T)>)
+where
+    G: IncidenceGraph,
+    F: Fn(Edge<G>) -> T,
+{
+    let index = vertex_index(g, g.vertices());
+    g.edges_with_ends()
+        .filter(|&(_, u, v)| u != v)
+        .map(|(e, u, v)| (e, (index[u], index[v], w(e))))
+        .unzip()
+}
+
+fn into_weighted_matching<G, T>(
+    g: &G,
+    edges: &[Edge<G>],
+    ends: &[(usize, usize, T)],
+    mate: &[usize],
+) -> (Vec<Edge<G>>, DefaultVertexPropMut<G, OptionVertex<G>>)
+where
+    G: IncidenceGraph,
+{
+    let vertices: Vec<_> = g.vertices().collect();
+    let mut matching = vec![];
+    let mut mate_prop = g.default_vertex_prop(G::vertex_none());
+    for (u, &k) in mate.iter().enumerate() {
+        if k == NONE {
+            continue;
+        }
+        let (a, b, _) = ends[k];
+        let v = if a == u { b } else { a };
+        mate_prop[vertices[u]] = G::vertex_some(vertices[v]);
+        if u < v {
+            matching.push(edges[k]);
+        }
+    }
+    (matching, mate_prop)
+}
+
+// Finds a maximum weight matching in the graph with n vertices and the edges (u, v, w). If
+// max_cardinality is true, finds a maximum weight matching between the maximum cardinality
+// matchings. Returns the index of the matched edge of each vertex or NONE.
+//
+// This is the primal-dual blossom algorithm as described by Galil in "Efficient Algorithms for
+// Finding Maximum Matching in Graphs" with the O(n^3) implementation of Joris van Rantwijk. The
+// dual variables are stored with twice their values, so only integer arithmetic is needed if the
+// weights are integers.
+fn weighted_matching<T>(n: usize, edges: &[(usize, usize, T)], max_cardinality: bool) -> Vec<usize>
+where
+    T: Copy + Ord + Zero + One + Sub<Output = T> + Div<Output = T>,
+{
+    let mut m = WeightedMatching::new(n, edges);
+    m.run(max_cardinality);
+    m.mate
+        .iter()
+        .map(|&p| if p == NONE { NONE } else { p / 2 })
+        .collect()
+}
+
+// Returns true if delta is None or x < delta.
+fn less<T: Ord>(x: T, delta: Option<T>) -> bool {
+    match delta {
+        Some(delta) => x < delta,
+        None => true,
+    }
+}
+
+// The vertices are numbered from 0 to n - 1 and the blossoms from n to 2n - 1. The endpoints of
+// the edge k are numbered 2k (the first end) and 2k + 1 (the second end), so p ^ 1 is the other
+// endpoint of the edge of p.
+struct WeightedMatching<'a, T: 'a> {
+    n: usize,
+    edges: &'a [(usize, usize, T)],
+    // the vertex of each endpoint
+    endpoint: Vec<usize>,
+    // the remote endpoints of the edges incident to each vertex
+    neighbend: Vec<Vec<usize>>,
+    // the remote endpoint of the matched edge of each vertex
+    mate: Vec<usize>,
+    // the label of each top level blossom (and vertex): 0 (free), 1 (S) or 2 (T), 5 is used as a
+    // temporary mark
+    label: Vec<u8>,
+    // the endpoint through which the label was assigned
+    labelend: Vec<usize>,
+    // the top level blossom of each vertex
+    inblossom: Vec<usize>,
+    parent: Vec<usize>,
+    // the sub blossoms of each blossom in cyclic order starting at the base
+    childs: Vec<Vec<usize>>,
+    base: Vec<usize>,
+    // endps[b][i] is the endpoint that connects childs[b][i] to childs[b][i + 1]
+    endps: Vec<Vec<usize>>,
+    // the least slack edge to a S blossom (for free vertices and S blossoms)
+    bestedge: Vec<usize>,
+    // the least slack edges to the others S blossoms (for S blossoms)
+    bestedges: Vec<Option<Vec<usize>>>,
+    unused: Vec<usize>,
+    dual: Vec<T>,
+    allowed: Vec<bool>,
+    queue: Vec<usize>,
+}
+
+impl<'a, T> WeightedMatching<'a, T>
+where
+    T: Copy + Ord + Zero + One + Sub<Output = T> + Div<Output = T>,
+{
+    fn new(n: usize, edges: &'a [(usize, usize, T)]) -> Self {
+        let mut endpoint = Vec::with_capacity(2 * edges.len());
+        let mut neighbend = vec![vec![]; n];
+        for (k, &(u, v, _)) in edges.iter().enumerate() {
+            endpoint.push(u);
+            endpoint.push(v);
+            neighbend[u].push(2 * k + 1);
+            neighbend[v].push(2 * k);
+        }
+        let max = edges.iter().map(|e| e.2).fold(T::zero(), |a, b| a.max(b));
+        let mut dual = vec![max; n];
+        dual.resize(2 * n, T::zero());
+        WeightedMatching {
+            n,
+            edges,
+            endpoint,
+            neighbend,
+            mate: vec![NONE; n],
+            label: vec![0; 2 * n],
+            labelend: vec![NONE; 2 * n],
+            inblossom: (0..n).collect(),
+            parent: vec![NONE; 2 * n],
+            childs: vec![vec![]; 2 * n],
+            base: (0..n).chain(vec![NONE; n]).collect(),
+            endps: vec![vec![]; 2 * n],
+            bestedge: vec![NONE; 2 * n],
+            bestedges: vec![None; 2 * n],
+            unused: (n..2 * n).collect(),
+            dual,
+            allowed: vec![false; edges.len()],
+            queue: vec![],
+        }
+    }
+
+    fn slack(&self, k: usize) -> T {
+        let (u, v, w) = self.edges[k];
+        self.dual[u] + self.dual[v] - w - w
+    }
+
+    fn leaves(&self, b: usize) -> Vec<usize> {
+        if b < self.n {
+            vec![b]
+        } else {
+            self.childs[b]
+                .iter()
+                .flat_map(|&t| self.leaves(t))
+                .collect()
+        }
+    }
+
+    // Assigns the label t to the top level blossom of w, p is the endpoint through which the
+    // label is assigned.
+    fn assign_label(&mut self, w: usize, t: u8, p: usize) {
+        let b = self.inblossom[w];
+        self.label[w] = t;
+        self.label[b] = t;
+        self.labelend[w] = p;
+        self.labelend[b] = p;
+        self.bestedge[w] = NONE;
+        self.bestedge[b] = NONE;
+        if t == 1 {
+            let leaves = self.leaves(b);
+            self.queue.extend(leaves);
+        } else {
+            // the mate of the base becomes S
+            let m = self.mate[self.base[b]];
+            self.assign_label(self.endpoint[m], 1, m ^ 1);
+        }
+    }
+
+    // Traces back from the S vertices v and w to find a new blossom or an augmenting path.
+    // Returns the base of the new blossom or NONE if an augmenting path was found.
+    fn scan_blossom(&mut self, mut v: usize, mut w: usize) -> usize {
+        let mut path = vec![];
+        let mut base = NONE;
+        while v != NONE {
+            let b = self.inblossom[v];
+            if self.label[b] & 4 != 0 {
+                base = self.base[b];
+                break;
+            }
+            path.push(b);
+            self.label[b] = 5;
+            if self.labelend[b] == NONE {
+                // the base of b is single
+                v = NONE;
+            } else {
+                v = self.endpoint[self.labelend[b]];
+                let b = self.inblossom[v];
+                // b is a T blossom, trace one more step
+                v = self.endpoint[self.labelend[b]];
+            }
+            if w != NONE {
+                ::std::mem::swap(&mut v, &mut w);
+            }
+        }
+        for b in path {
+            self.label[b] = 1;
+        }
+        base
+    }
+
+    // Creates a new blossom with base base and the edge k, which connects two S vertices.
+    fn add_blossom(&mut self, base: usize, k: usize) {
+        let (v, w, _) = self.edges[k];
+        let bb = self.inblossom[base];
+        let mut bv = self.inblossom[v];
+        let mut bw = self.inblossom[w];
+        let b = self.unused.pop().unwrap();
+        self.base[b] = base;
+        self.parent[b] = NONE;
+        self.parent[bb] = b;
+        let mut path = vec![];
+        let mut endps = vec![];
+        while bv != bb {
+            self.parent[bv] = b;
+            path.push(bv);
+            endps.push(self.labelend[bv]);
+            bv = self.inblossom[self.endpoint[self.labelend[bv]]];
+        }
+        path.push(bb);
+        path.reverse();
+        endps.reverse();
+        endps.push(2 * k);
+        while bw != bb {
+            self.parent[bw] = b;
+            path.push(bw);
+            endps.push(self.labelend[bw] ^ 1);
+            bw = self.inblossom[self.endpoint[self.labelend[bw]]];
+        }
+        self.label[b] = 1;
+        self.labelend[b] = self.labelend[bb];
+        self.dual[b] = T::zero();
+        for v in self.leaves_of(&path) {
+            if self.label[self.inblossom[v]] == 2 {
+                // the T vertices become S
+                self.queue.push(v);
+            }
+            self.inblossom[v] = b;
+        }
+        // computes the least slack edges to the others S blossoms
+        let mut bestedgeto = vec![NONE; 2 * self.n];
+        for &bv in &path {
+            let list = match self.bestedges[bv].take() {
+                Some(list) => list,
+                None => self
+                    .leaves(bv)
+                    .into_iter()
+                    .flat_map(|v| self.neighbend[v].iter().map(|&p| p / 2))
+                    .collect(),
+            };
+            for k in list {
+                let (i, j, _) = self.edges[k];
+                let j = if self.inblossom[j] == b { i } else { j };
+                let bj = self.inblossom[j];
+                if bj != b
+                    && self.label[bj] == 1
+                    && (bestedgeto[bj] == NONE || self.slack(k) < self.slack(bestedgeto[bj]))
+                {
+                    bestedgeto[bj] = k;
+                }
+            }
+            self.bestedge[bv] = NONE;
+        }
+        let list: Vec<_> = bestedgeto.into_iter().filter(|&k| k != NONE).collect();
+        self.bestedge[b] = NONE;
+        for &k in &list {
+            if self.bestedge[b] == NONE || self.slack(k) < self.slack(self.bestedge[b]) {
+                self.bestedge[b] = k;
+            }
+        }
+        self.bestedges[b] = Some(list);
+        self.childs[b] = path;
+        self.endps[b] = endps;
+    }
+
+    fn leaves_of(&self, blossoms: &[usize]) -> Vec<usize> {
+        blossoms.iter().flat_map(|&b| self.leaves(b)).collect()
+    }
+
+    // Expands the blossom b. If endstage is true, the sub blossoms with zero dual variable are
+    // also expanded.
+    fn expand_blossom(&mut self, b: usize, endstage: bool) {
+        let childs = ::std::mem::take(&mut self.childs[b]);
+        let endps = ::std::mem::take(&mut self.endps[b]);
+        for &s in &childs {
+            self.parent[s] = NONE;
+            if s < self.n {
+                self.inblossom[s] = s;
+            } else if endstage && self.dual[s] == T::zero() {
+                self.expand_blossom(s, endstage);
+            } else {
+                for v in self.leaves(s) {
+                    self.inblossom[v] = s;
+                }
+            }
+        }
+        if !endstage && self.label[b] == 2 {
+            // relabels the sub blossoms on the even path from the entry child to the base
+            let len = childs.len() as isize;
+            let child = |j: isize| childs[j.rem_euclid(len) as usize];
+            let endp = |j: isize| endps[j.rem_euclid(len) as usize];
+            let entry = self.inblossom[self.endpoint[self.labelend[b] ^ 1]];
+            let mut j = childs.iter().position(|&c| c == entry).unwrap() as isize;
+            let (step, trick) = if j & 1 == 1 {
+                j -= len;
+                (1, 0)
+            } else {
+                (-1, 1)
+            };
+            let mut p = self.labelend[b];
+            while j != 0 {
+                let q = endp(j - trick as isize) ^ trick;
+                self.label[self.endpoint[p ^ 1]] = 0;
+                self.label[self.endpoint[q ^ 1]] = 0;
+                self.assign_label(self.endpoint[p ^ 1], 2, p);
+                self.allowed[q / 2] = true;
+                j += step;
+                p = endp(j - trick as isize) ^ trick;
+                self.allowed[p / 2] = true;
+                j += step;
+            }
+            let bv = child(j);
+            let v = self.endpoint[p ^ 1];
+            self.label[v] = 2;
+            self.label[bv] = 2;
+            self.labelend[v] = p;
+            self.labelend[bv] = p;
+            self.bestedge[bv] = NONE;
+            j += step;
+            // relabels the sub blossoms on the odd path that are reachable from outside
+            while child(j) != entry {
+                let bv = child(j);
+                j += step;
+                if self.label[bv] == 1 {
+                    continue;
+                }
+                if let Some(v) = self.leaves(bv).into_iter().find(|&v| self.label[v] != 0) {
+                    self.label[v] = 0;
+                    let m = self.mate[self.base[bv]];
+                    self.label[self.endpoint[m]] = 0;
+                    let p = self.labelend[v];
+                    self.assign_label(v, 2, p);
+                }
+            }
+        }
+        self.label[b] = 0;
+        self.labelend[b] = NONE;
+        self.base[b] = NONE;
+        self.bestedges[b] = None;
+        self.bestedge[b] = NONE;
+        self.unused.push(b);
+    }
+
+    // Swaps the matched and unmatched edges on the even path from the vertex v to the base of
+    // the blossom b, so v becomes the base.
+    fn augment_blossom(&mut self, b: usize, v: usize) {
+        let mut t = v;
+        while self.parent[t] != b {
+            t = self.parent[t];
+        }
+        if t >= self.n {
+            self.augment_blossom(t, v);
+        }
+        let len = self.childs[b].len() as isize;
+        let i = self.childs[b].iter().position(|&c| c == t).unwrap();
+        let mut j = i as isize;
+        let (step, trick) = if i & 1 == 1 {
+            j -= len;
+            (1, 0)
+        } else {
+            (-1, 1)
+        };
+        while j != 0 {
+            j += step;
+            let t = self.childs[b][j.rem_euclid(len) as usize];
+            let p = self.endps[b][(j - trick as isize).rem_euclid(len) as usize] ^ trick;
+            if t >= self.n {
+                self.augment_blossom(t, self.endpoint[p]);
+            }
+            j += step;
+            let t = self.childs[b][j.rem_euclid(len) as usize];
+            if t >= self.n {
+                self.augment_blossom(t, self.endpoint[p ^ 1]);
+            }
+            self.mate[self.endpoint[p]] = p ^ 1;
+            self.mate[self.endpoint[p ^ 1]] = p;
+        }
+        self.childs[b].rotate_left(i);
+        self.endps[b].rotate_left(i);
+        self.base[b] = self.base[self.childs[b][0]];
+    }
+
+    // Augments the matching through the augmenting path that uses the edge k.
+    fn augment_matching(&mut self, k: usize) {
+        let (v, w, _) = self.edges[k];
+        for &(mut s, mut p) in &[(v, 2 * k + 1), (w, 2 * k)] {
+            loop {
+                let bs = self.inblossom[s];
+                if bs >= self.n {
+                    self.augment_blossom(bs, s);
+                }
+                self.mate[s] = p;
+                if self.labelend[bs] == NONE {
+                    break;
+                }
+                let t = self.endpoint[self.labelend[bs]];
+                let bt = self.inblossom[t];
+                s = self.endpoint[self.labelend[bt]];
+                let j = self.endpoint[self.labelend[bt] ^ 1];
+                if bt >= self.n {
+                    self.augment_blossom(bt, j);
+                }
+                self.mate[j] = self.labelend[bt];
+                p = self.labelend[bt] ^ 1;
+            }
+        }
+    }
+
+    fn run(&mut self, max_cardinality: bool) {
+        let n = self.n;
+        let two = T::one() + T::one();
+        for _ in 0..n {
+            // starts a new stage
+            self.label = vec![0; 2 * n];
+            self.bestedge = vec![NONE; 2 * n];
+            for b in n..2 * n {
+                self.bestedges[b] = None;
+            }
+            self.allowed = vec![false; self.edges.len()];
+            self.queue.clear();
+            for v in 0..n {
+                if self.mate[v] == NONE && self.label[self.inblossom[v]] == 0 {
+                    self.assign_label(v, 1, NONE);
+                }
+            }
+            let mut augmented = false;
+            loop {
+                while let Some(v) = self.queue.pop() {
+                    for i in 0..self.neighbend[v].len() {
+                        let p = self.neighbend[v][i];
+                        let k = p / 2;
+                        let w = self.endpoint[p];
+                        if self.inblossom[v] == self.inblossom[w] {
+                            continue;
+                        }
+                        let mut kslack = T::zero();
+                        if !self.allowed[k] {
+                            kslack = self.slack(k);
+                            if kslack <= T::zero() {
+                                self.allowed[k] = true;
+                            }
+                        }
+                        let bw = self.inblossom[w];
+                        if self.allowed[k] {
+                            if self.label[bw] == 0 {
+                                self.assign_label(w, 2, p ^ 1);
+                            } else if self.label[bw] == 1 {
+                                let base = self.scan_blossom(v, w);
+                                if base != NONE {
+                                    self.add_blossom(base, k);
+                                } else {
+                                    self.augment_matching(k);
+                                    augmented = true;
+                                    break;
+                                }
+                            } else if self.label[w] == 0 {
+                                self.label[w] = 2;
+                                self.labelend[w] = p ^ 1;
+                            }
+                        } else if self.label[bw] == 1 {
+                            let b = self.inblossom[v];
+                            if self.bestedge[b] == NONE || kslack < self.slack(self.bestedge[b]) {
+                                self.bestedge[b] = k;
+                            }
+                        } else if self.label[w] == 0
+                            && (self.bestedge[w] == NONE || kslack < self.slack(self.bestedge[w]))
+                        {
+                            self.bestedge[w] = k;
+                        }
+                    }
+                    if augmented {
+                        break;
+                    }
+                }
+                if augmented {
+                    break;
+                }
+                // no augmenting path with the allowed edges, updates the dual variables
+                let mut delta = None;
+                let mut kind = 0;
+                let mut target = NONE;
+                if !max_cardinality {
+                    // the minimum dual of the vertices, stops if it goes to zero
+                    delta = self.dual[..n].iter().copied().min();
+                    kind = 1;
+                }
+                for v in 0..n {
+                    if self.label[self.inblossom[v]] == 0 && self.bestedge[v] != NONE {
+                        let d = self.slack(self.bestedge[v]);
+                        if less(d, delta) {
+                            delta = Some(d);
+                            kind = 2;
+                            target = self.bestedge[v];
+                        }
+                    }
+                }
+                for b in 0..2 * n {
+                    if self.parent[b] == NONE && self.label[b] == 1 && self.bestedge[b] != NONE {
+                        let d = self.slack(self.bestedge[b]) / two;
+                        if less(d, delta) {
+                            delta = Some(d);
+                            kind = 3;
+                            target = self.bestedge[b];
+                        }
+                    }
+                }
+                for b in n..2 * n {
+                    if self.base[b] != NONE
+                        && self.parent[b] == NONE
+                        && self.label[b] == 2
+                        && less(self.dual[b], delta)
+                    {
+                        delta = Some(self.dual[b]);
+                        kind = 4;
+                        target = b;
+                    }
+                }
+                let delta = match delta {
+                    Some(delta) => delta,
+                    None => {
+                        // no further improvement is possible
+                        kind = 1;
+                        let min = self.dual[..n].iter().copied().min();
+                        min.unwrap_or_else(T::zero).max(T::zero())
+                    }
+                };
+                for v in 0..n {
+                    match self.label[self.inblossom[v]] {
+                        1 => self.dual[v] = self.dual[v] - delta,
+                        2 => self.dual[v] = self.dual[v] + delta,
+                        _ => (),
+                    }
+                }
+                for b in n..2 * n {
+                    if self.base[b] != NONE && self.parent[b] == NONE {
+                        match self.label[b] {
+                            1 => self.dual[b] = self.dual[b] + delta,
+                            2 => self.dual[b] = self.dual[b] - delta,
+                            _ => (),
+                        }
+                    }
+                }
+                match kind {
+                    1 => break,
+                    2 => {
+                        self.allowed[target] = true;
+                        let (i, j, _) = self.edges[target];
+                        let i = if self.label[self.inblossom[i]] == 0 {
+                            j
+                        } else {
+                            i
+                        };
+                        self.queue.push(i);
+                    }
+                    3 => {
+                        self.allowed[target] = true;
+                        let (i, _, _) = self.edges[target];
+                        self.queue.push(i);
+                    }
+                    _ => self.expand_blossom(target, false),
+                }
+            }
+            if !augmented {
+                break;
+            }
+            // expands the S blossoms with zero dual variable
+            for b in n..2 * n {
+                if self.parent[b] == NONE
+                    && self.base[b] != NONE
+                    && self.label[b] == 1
+                    && self.dual[b] == T::zero()
+                {
+                    self.expand_blossom(b, true);
+                }
+            }
+        }
+    }
+}
+
+// A graph where the vertices are numbered from 0 to n - 1 and adj[u] has the pairs (v, e) for each
+// edge e = (u, v) of the original graph. mate_edge[u] is the index of the matched edge of u.
+struct Net {
+    adj: Vec<Vec<(usize, usize)>>,
+    mate: Vec<usize>,
+    mate_edge: Vec<usize>,
+}
+
+impl Net {
+    // Creates a net with the edges (u, v) of g such that keep(u, v) is true.
+    fn new<G, F>(g: &G, keep: F) -> Self
+    where
+        G: IncidenceGraph,
+        F: Fn(Vertex<G>, Vertex<G>) -> bool,
+    {
+        let index = vertex_index(g, g.vertices());
+        let n = g.num_vertices();
+        let mut adj = vec![vec![]; n];
+        for (i, (u, v)) in g.edges_ends().enumerate() {
+            if keep(u, v) {
+                adj[index[u]].push((index[v], i));
+                adj[index[v]].push((index[u], i));
+            }
+        }
+        Net {
+            adj,
+            mate: vec![NONE; n],
+            mate_edge: vec![NONE; n],
+        }
+    }
+
+    fn hopcroft_karp(&mut self, left: Vec<usize>) {
+        let n = self.adj.len();
+        let mut dist = vec![NONE; n];
+        let mut next = vec![0; n];
+        let mut queue = VecDeque::new();
+        loop {
+            // computes the layers of alternating paths that start in free left vertices
+            let mut found = false;
+            for &u in &left {
+                if self.mate[u] == NONE {
+                    dist[u] = 0;
+                    queue.push_back(u);
+                } else {
+                    dist[u] = NONE;
+                }
+            }
+            while let Some(u) = queue.pop_front() {
+                for &(v, _) in &self.adj[u] {
+                    let w = self.mate[v];
+                    if w == NONE {
+                        found = true;
+                    } else if dist[w] == NONE {
+                        dist[w] = dist[u] + 1;
+                        queue.push_back(w);
+                    }
+                }
+            }
+            if !found {
+                break;
+            }
+            // finds a maximal set of vertex disjoint shortest augmenting paths
+            for &u in &left {
+                next[u] = 0;
+            }
+            for &r in &left {
+                if self.mate[r] != NONE {
+                    continue;
+                }
+                let mut stack = vec![r];
+                while let Some(&u) = stack.last() {
+                    if next[u] == self.adj[u].len() {
+                        // u is a dead end
+                        dist[u] = NONE;
+                        stack.pop();
+                        if let Some(&p) = stack.last() {
+                            next[p] += 1;
+                        }
+                        continue;
+                    }
+                    let (v, _) = self.adj[u][next[u]];
+                    let w = self.mate[v];
+                    if w == NONE {
+                        for &u in &stack {
+                            let (v, e) = self.adj[u][next[u]];
+                            self.set_mate(u, v, e);
+                        }
+                        break;
+                    } else if dist[w] == dist[u] + 1 {
+                        stack.push(w);
+                    } else {
+                        next[u] += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    fn edmonds(&mut self) {
+        let n = self.adj.len();
+        for r in 0..n {
+            if self.mate[r] != NONE {
+                continue;
+            }
+            let (mut v, p) = self.find_augmenting_path(r);
+            // flips the path
+            while v != NONE {
+                let (pv, e) = p[v];
+                let next = self.mate[pv];
+                self.set_mate(v, pv, e);
+                v = next;
+            }
+        }
+    }
+
+    // Searches an augmenting path that starts in the free vertex r. Returns the end of the path
+    // (or NONE if there is no such path) and the predecessor of each vertex in the search, that
+    // is, the vertex reached by an unmatched edge and the edge.
+    fn find_augmenting_path(&self, r: usize) -> (usize, Vec<(usize, usize)>) {
+        let n = self.adj.len();
+        let mut base: Vec<usize> = (0..n).collect();
+        let mut p = vec![(NONE, NONE); n];
+        let mut used = vec![false; n];
+        let mut queue = VecDeque::new();
+        used[r] = true;
+        queue.push_back(r);
+        while let Some(u) = queue.pop_front() {
+            for &(v, e) in &self.adj[u] {
+                if base[u] == base[v] || self.mate[u] == v {
+                    continue;
+                }
+                if v == r || self.mate[v] != NONE && p[self.mate[v]].0 != NONE {
+                    // u and v are even vertices, so a blossom was found and it is contracted
+                    let b = self.lca(&base, &p, u, v);
+                    let mut blossom = vec![false; n];
+                    self.mark_path(&base, &mut p, &mut blossom, u, b, (v, e));
+                    self.mark_path(&base, &mut p, &mut blossom, v, b, (u, e));
+                    for i in 0..n {
+                        if blossom[base[i]] {
+                            base[i] = b;
+                            if !used[i] {
+                                used[i] = true;
+                                queue.push_back(i);
+                            }
+                        }
+                    }
+                } else if p[v].0 == NONE {
+                    p[v] = (u, e);
+                    if self.mate[v] == NONE {
+                        return (v, p);
+                    }
+                    let w = self.mate[v];
+                    used[w] = true;
+                    queue.push_back(w);
+                }
+            }
+        }
+        (NONE, p)
+    }
+
+    // Finds the base of the blossom that contains u and v.
+    fn lca(&self, base: &[usize], p: &[(usize, usize)], mut u: usize, mut v: usize) -> usize {
+        let mut visited = vec![false; self.adj.len()];
+        loop {
+            u = base[u];
+            visited[u] = true;
+            if self.mate[u] == NONE {
+                break;
+            }
+            u = p[self.mate[u]].0;
+        }
+        loop {
+            v = base[v];
+            if visited[v] {
+                return v;
+            }
+            v = p[self.mate[v]].0;
+        }
+    }
+
+    // Marks the vertices in the path from u to the base b of the blossom and makes the
+    // predecessors of the path point back to child, so augmenting paths can cross the blossom.
+    fn mark_path(
+        &self,
+        base: &[usize],
+        p: &mut [(usize, usize)],
+        blossom: &mut [bool],
+        mut u: usize,
+        b: usize,
+        mut child: (usize, usize),
+    ) {
+        while base[u] != b {
+            let w = self.mate[u];
+            blossom[base[u]] = true;
+            blossom[base[w]] = true;
+            p[u] = child;
+            child = (w, p[w].1);
+            u = p[w].0;
+        }
+    }
+
+    fn set_mate(&mut self, u: usize, v: usize, e: usize) {
+        self.mate[u] = v;
+        self.mate[v] = u;
+        self.mate_edge[u] = e;
+        self.mate_edge[v] = e;
+    }
+
+    fn into_matching<G>(self, g: &G) -> (Vec<Edge<G>>, DefaultVertexPropMut<G, OptionVertex<G>>)
+    where
+        G: IncidenceGraph,
+    {
+        let vertices: Vec<_> = g.vertices().collect();
+        let mut mate = g.default_vertex_prop(G::vertex_none());
+        for (u, &v) in self.mate.iter().enumerate() {
+            if v != NONE {
+                mate[vertices[u]] = G::vertex_some(vertices[v]);
+            }
+        }
+        let mut matched = vec![false; g.num_edges()];
+        for (u, &e) in self.mate_edge.iter().enumerate() {
+            if e != NONE && u < self.mate[u] {
+                matched[e] = true;
+            }
+        }
+        let edges = g
+            .edges()
+            .zip(matched)
+            .filter(|&(_, m)| m)
+            .map(|(e, _)| e)
+            .collect();
+        (edges, mate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fera_fun::vec;
+
+    fn check<G: IncidenceGraph>(
+        g: &G,
+        matching: &[Edge<G>],
+        mate: &DefaultVertexPropMut<G, OptionVertex<G>>,
+    ) {
+        let mut covered = g.default_vertex_prop(false);
+        for &e in matching {
+            let (u, v) = g.ends(e);
+            assert!(!covered[u] && !covered[v]);
+            covered[u] = true;
+            covered[v] = true;
+            assert_eq!(Some(v), mate[u].into_option());
+            assert_eq!(Some(u), mate[v].into_option());
+        }
+        for v in g.vertices() {
+            assert_eq!(covered[v], mate[v].into_option().is_some());
+        }
+    }
+
+    #[test]
+    fn hopcroft_karp() {
+        // a greedy matching that takes (0, 4) first is not maximum
+        let g: StaticGraph = graph!(
+            8,
+            (0, 4),
+            (0, 5),
+            (1, 4),
+            (2, 5),
+            (2, 6),
+            (3, 6),
+            (3, 7),
+            (1, 7)
+        );
+        let (matching, mate) = g.hopcroft_karp(FnProp(|v| v >= 4));
+        check(&g, &matching, &mate);
+        assert_eq!(4, matching.len());
+        assert_eq!(matching.len(), g.bipartite_matching().unwrap().0.len());
+        // the edge (0, 1) is ignored
+        let g: StaticGraph = graph!(3, (0, 1), (1, 2));
+        let v = vec(g.vertices());
+        let (matching, mate) = g.hopcroft_karp(FnProp(|v| v == 2));
+        check(&g, &matching, &mate);
+        assert_eq!(vec![g.edge_by_ends(v[1], v[2])], matching);
+    }
+
+    #[test]
+    fn edmonds() {
+        // the augmenting path 5 - 4 - 3 - 1 - 2 - 0 - 6 goes through the blossom 0 - 1 - 2
+        let g: StaticGraph = graph!(7, (0, 1), (1, 2), (2, 0), (1, 3), (3, 4), (4, 5), (0, 6));
+        let (matching, mate) = g.edmonds();
+        check(&g, &matching, &mate);
+        assert_eq!(3, matching.len());
+
+        // the petersen graph has a perfect matching
+        let g: StaticGraph = graph!(
+            10,
+            (0, 1),
+            (1, 2),
+            (2, 3),
+            (3, 4),
+            (4, 0),
+            (0, 5),
+            (1, 6),
+            (2, 7),
+            (3, 8),
+            (4, 9),
+            (5, 7),
+            (7, 9),
+            (9, 6),
+            (6, 8),
+            (8, 5)
+        );
+        let (matching, mate) = g.edmonds();
+        check(&g, &matching, &mate);
+        assert_eq!(5, matching.len());
+    }
+
+    #[test]
+    fn hungarian() {
+        let (g, w): (StaticGraph, _) = graph!(
+            6,
+            (0, 3) -> 7i64,
+            (0, 4) -> 2,
+            (1, 3) -> 3,
+            (1, 4) -> 6,
+            (1, 5) -> 1,
+            (2, 4) -> 4,
+            (2, 5) -> 9
+        );
+        let v = vec(g.vertices());
+        let a = g.hungarian(FnProp(|v| v >= 3), &w).unwrap();
+        check(&g, &a.edges, &a.mate);
+        assert_eq!(7 + 1 + 4, a.cost);
+        assert_eq!(Some(v[5]), a.mate[v[1]].into_option());
+        for (e, x, y) in g.edges_with_ends() {
+            assert!(a.potential[x] + a.potential[y] <= w[e]);
+        }
+        // 0 and 1 can only be matched with 2
+        let g: StaticGraph = graph!(4, (0, 2), (1, 2), (0, 1));
+        assert!(g
+            .hungarian(FnProp(|v| v >= 2), g.default_edge_prop(1i64))
+            .is_none());
+        // more rows than columns
+        assert!(g
+            .hungarian(FnProp(|v| v >= 3), g.default_edge_prop(1i64))
+            .is_none());
+    }
+
+    #[test]
+    fn weighted_matching() {
+        // the maximum weight matching is not a maximum matching
+        let (g, w): (StaticGraph, _) = graph!(
+            6,
+            (0, 1) -> 8i64,
+            (1, 2) -> 9,
+            (2, 0) -> 10,
+            (2, 3) -> 1,
+            (0, 4) -> 1,
+            (4, 5) -> 1
+        );
+        let v = vec(g.vertices());
+        let (mut matching, mate) = g.max_weight_matching(&w);
+        check(&g, &matching, &mate);
+        matching.sort();
+        let mut expected = vec![g.edge_by_ends(v[2], v[0]), g.edge_by_ends(v[4], v[5])];
+        expected.sort();
+        assert_eq!(expected, matching);
+        let (matching, mate) = g.min_weight_perfect_matching(&w).unwrap();
+        check(&g, &matching, &mate);
+        assert_eq!(8 + 1 + 1, matching.iter().map(|&e| w[e]).sum::<i64>());
+    }
+
+    #[test]
+    fn hungarian_matrix() {
+        let cost = vec![vec![8, 4, 7, 1], vec![5, 2, 3, 6], vec![9, 4, 8, 2]];
+        let a = super::hungarian(&cost);
+        assert_eq!(vec![3, 2, 1], a.assignment);
+        assert_eq!(1 + 3 + 4, a.cost);
+        for (i, row) in cost.iter().enumerate() {
+            for (j, &c) in row.iter().enumerate() {
+                let p = a.row_potential[i] + a.col_potential[j];
+                assert!(p <= c);
+                assert!(a.assignment[i] != j || p == c);
+            }
+        }
+    }
+}
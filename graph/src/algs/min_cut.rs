@@ -0,0 +1,350 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Global minimum cut of undirected graphs.
+//!
+//! A cut is a partition of the vertices in two non empty sets, its weight is the sum of the
+//! weights of the edges with one end in each set. The algorithms return the weight of a minimum
+//! cut and the vertices of one of the sets, or `None` if the graph has less than two vertices.
+//!
+//! The weights are read to a matrix at the beginning of each algorithm, the parallel edges are
+//! combined and the loops are ignored.
+
+use algs::index::vertex_index;
+use prelude::*;
+
+use num_traits::Zero;
+use rand::distributions::uniform::SampleUniform;
+use rand::Rng;
+
+use std::mem;
+use std::ops::Sub;
+
+pub trait MinCut: Incidence {
+    /// Finds a minimum cut using the Stoer-Wagner algorithm with the (non negative) edge weights
+    /// `w`.
+    ///
+    /// The running time is `O(n^3)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate fera_graph;
+    ///
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::MinCut;
+    ///
+    /// # fn main() {
+    /// // two triangles connected by two light edges
+    /// let (g, w): (StaticGraph, _) = graph!(
+    ///     6,
+    ///     (0, 1) -> 3u32,
+    ///     (1, 2) -> 3,
+    ///     (2, 0) -> 3,
+    ///     (3, 4) -> 3,
+    ///     (4, 5) -> 3,
+    ///     (5, 3) -> 3,
+    ///     (0, 3) -> 1,
+    ///     (2, 5) -> 2
+    /// );
+    /// let (weight, mut side) = g.stoer_wagner(&w).unwrap();
+    /// assert_eq!(3, weight);
+    /// side.sort();
+    /// assert!(side == vec![0, 1, 2] || side == vec![3, 4, 5]);
+    /// # }
+    /// ```
+    fn stoer_wagner<W, T>(&self, w: W) -> Option<(T, Vec<Vertex<Self>>)>
+    where
+        Self: Graph,
+        W: EdgePropGet<Self, T>,
+        T: Copy + Ord + Zero,
+    {
+        let (vertices, mut m) = weight_matrix(self, &w);
+        let n = vertices.len();
+        if n < 2 {
+            return None;
+        }
+        let mut groups: Vec<_> = (0..n).map(|v| vec![v]).collect();
+        let mut active: Vec<_> = (0..n).collect();
+        let mut best: Option<(T, Vec<usize>)> = None;
+        while active.len() > 1 {
+            // adds the vertices in the order of the most tightly connected to the added ones, the
+            // last two are s and t and the cut of the phase separates t from the others
+            let k = active.len();
+            let mut key = vec![T::zero(); k];
+            let mut added = vec![false; k];
+            let (mut s, mut t) = (0, 0);
+            for _ in 0..k {
+                let u = (0..k)
+                    .filter(|&i| !added[i])
+                    .max_by_key(|&i| key[i])
+                    .unwrap();
+                added[u] = true;
+                s = t;
+                t = u;
+                for i in (0..k).filter(|&i| !added[i]) {
+                    key[i] = key[i] + m[active[u]][active[i]];
+                }
+            }
+            match best {
+                Some((weight, _)) if weight <= key[t] => (),
+                _ => best = Some((key[t], groups[active[t]].clone())),
+            }
+            // merges t into s
+            let (s, t) = (active[s], active.swap_remove(t));
+            let row: Vec<_> = m[s].iter().zip(&m[t]).map(|(&a, &b)| a + b).collect();
+            for (x, &weight) in row.iter().enumerate() {
+                m[x][s] = weight;
+            }
+            m[s] = row;
+            let group = mem::take(&mut groups[t]);
+            groups[s].extend(group);
+        }
+        best.map(|(weight, side)| (weight, side.into_iter().map(|i| vertices[i]).collect()))
+    }
+
+    /// Finds a minimum cut with high probability using the Karger-Stein recursive contraction
+    /// algorithm with the (non negative) edge weights `w`. The random choices are made with
+    /// `rng`.
+    ///
+    /// The recursive contraction is repeated `O(log^2 n)` times and the lightest cut found is
+    /// returned, so the probability that the cut is not minimum is `O(1/n)`. The returned weight
+    /// is always the weight of the returned cut.
+    ///
+    /// The running time is `O(n^2 log^3 n)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate fera_graph;
+    /// extern crate rand;
+    ///
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::MinCut;
+    /// use rand::prelude::*;
+    ///
+    /// # fn main() {
+    /// // a cycle with a heavy chord, the minimum cut removes two edges of weight 1
+    /// let (g, w): (StaticGraph, _) = graph!(
+    ///     8,
+    ///     (0, 1) -> 1u32,
+    ///     (1, 2) -> 1,
+    ///     (2, 3) -> 5,
+    ///     (3, 4) -> 5,
+    ///     (4, 5) -> 5,
+    ///     (5, 6) -> 5,
+    ///     (6, 7) -> 5,
+    ///     (7, 0) -> 5,
+    ///     (0, 4) -> 9
+    /// );
+    /// let (weight, _) = g.karger_stein(&w, SmallRng::seed_from_u64(42)).unwrap();
+    /// assert_eq!(2, weight);
+    /// # }
+    /// ```
+    fn karger_stein<W, T, R>(&self, w: W, mut rng: R) -> Option<(T, Vec<Vertex<Self>>)>
+    where
+        Self: Graph,
+        W: EdgePropGet<Self, T>,
+        T: Copy + Ord + Zero + Sub<Output = T> + SampleUniform,
+        R: Rng,
+    {
+        let (vertices, m) = weight_matrix(self, &w);
+        let n = vertices.len();
+        if n < 2 {
+            return None;
+        }
+        let log = (n as f64).log2().ceil() as usize;
+        let groups: Vec<_> = (0..n).map(|v| vec![v]).collect();
+        let (weight, side) = (0..log * log)
+            .map(|_| recursive_contraction(m.clone(), groups.clone(), &mut rng))
+            .min_by_key(|c| c.0)
+            .unwrap();
+        Some((weight, side.into_iter().map(|i| vertices[i]).collect()))
+    }
+}
+
+impl<G: Incidence> MinCut for G {}
+
+// Returns the vertices and the matrix with the sum of the weights of the edges between each pair
+// of vertices.
+fn weight_matrix<G, W, T>(g: &G, w: &W) -> (Vec<Vertex<G>>, Vec<Vec<T>>)
+where
+    G: Graph,
+    W: EdgePropGet<G, T>,
+    T: Copy + Zero,
+{
+    let vertices: Vec<_> = g.vertices().collect();
+    let index = vertex_index(g, &vertices);
+    let n = vertices.len();
+    let mut m = vec![vec![T::zero(); n]; n];
+    for (e, u, v) in g.edges_with_ends() {
+        let (u, v) = (index[u], index[v]);
+        if u != v {
+            m[u][v] = m[u][v] + w.get(e);
+            m[v][u] = m[u][v];
+        }
+    }
+    (vertices, m)
+}
+
+// Finds a cut of the graph with the weight matrix m where the vertex i represents the vertices
+// groups[i] of the original graph.
+fn recursive_contraction<T, R>(
+    m: Vec<Vec<T>>,
+    groups: Vec<Vec<usize>>,
+    rng: &mut R,
+) -> (T, Vec<usize>)
+where
+    T: Copy + Ord + Zero + Sub<Output = T> + SampleUniform,
+    R: Rng,
+{
+    let k = m.len();
+    let degree: Vec<_> = m.iter().map(|row| sum(row)).collect();
+    if let Some(i) = (0..k).find(|&i| degree[i] == T::zero()) {
+        return (T::zero(), groups[i].clone());
+    }
+    if k <= 6 {
+        return min_cut_naive(&m, &groups);
+    }
+    let t = (k as f64 / 2f64.sqrt()).ceil() as usize + 1;
+    let a = contract(m.clone(), groups.clone(), degree.clone(), t, rng);
+    let a = recursive_contraction(a.0, a.1, rng);
+    let b = contract(m, groups, degree, t, rng);
+    let b = recursive_contraction(b.0, b.1, rng);
+    if a.0 <= b.0 {
+        a
+    } else {
+        b
+    }
+}
+
+// Contracts random edges, chosen with probability proportional to their weights, until there are
+// t vertices or a vertex with zero degree.
+fn contract<T, R>(
+    mut m: Vec<Vec<T>>,
+    mut groups: Vec<Vec<usize>>,
+    mut degree: Vec<T>,
+    t: usize,
+    rng: &mut R,
+) -> (Vec<Vec<T>>, Vec<Vec<usize>>)
+where
+    T: Copy + Ord + Zero + Sub<Output = T> + SampleUniform,
+    R: Rng,
+{
+    while m.len() > t && degree.iter().all(|&d| d != T::zero()) {
+        let u = choose(&degree, rng);
+        let v = choose(&m[u], rng);
+        let k = m.len();
+        let uv = m[u][v];
+        for x in (0..k).filter(|&x| x != u && x != v) {
+            m[u][x] = m[u][x] + m[v][x];
+            m[x][u] = m[u][x];
+        }
+        degree[u] = degree[u] + degree[v] - uv - uv;
+        let group = mem::take(&mut groups[v]);
+        groups[u].extend(group);
+        m.swap_remove(v);
+        for row in &mut m {
+            row.swap_remove(v);
+        }
+        degree.swap_remove(v);
+        groups.swap_remove(v);
+    }
+    (m, groups)
+}
+
+// Returns an index i with probability proportional to values[i].
+fn choose<T, R>(values: &[T], rng: &mut R) -> usize
+where
+    T: Copy + Ord + Zero + Sub<Output = T> + SampleUniform,
+    R: Rng,
+{
+    let mut r = rng.gen_range(T::zero(), sum(values));
+    for (i, &x) in values.iter().enumerate() {
+        if r < x {
+            return i;
+        }
+        r = r - x;
+    }
+    unreachable!()
+}
+
+fn sum<T: Copy + Zero>(values: &[T]) -> T {
+    values.iter().fold(T::zero(), |acc, &x| acc + x)
+}
+
+// Finds a minimum cut by trying all the partitions.
+fn min_cut_naive<T>(m: &[Vec<T>], groups: &[Vec<usize>]) -> (T, Vec<usize>)
+where
+    T: Copy + Ord + Zero,
+{
+    let k = m.len();
+    // the last vertex is always in the other side
+    let (weight, mask) = (1..1usize << (k - 1))
+        .map(|mask| {
+            let mut weight = T::zero();
+            for i in (0..k).filter(|&i| mask & (1 << i) != 0) {
+                for j in (0..k).filter(|&j| mask & (1 << j) == 0) {
+                    weight = weight + m[i][j];
+                }
+            }
+            (weight, mask)
+        })
+        .min()
+        .unwrap();
+    let side = (0..k)
+        .filter(|&i| mask & (1 << i) != 0)
+        .flat_map(|i| groups[i].iter().cloned())
+        .collect();
+    (weight, side)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::SmallRng;
+    use rand::SeedableRng;
+
+    fn sorted(mut side: Vec<u32>) -> Vec<u32> {
+        side.sort();
+        side
+    }
+
+    #[test]
+    fn min_cut() {
+        // a path with parallel edges between 1 and 2 and a loop
+        let (g, w): (StaticGraph, _) = graph!(
+            4,
+            (0, 1) -> 4u32,
+            (1, 2) -> 2,
+            (2, 3) -> 5,
+            (1, 2) -> 1,
+            (3, 3) -> 1
+        );
+        let mut rng = SmallRng::seed_from_u64(0);
+        for &(weight, ref side) in &[
+            g.stoer_wagner(&w).unwrap(),
+            g.karger_stein(&w, &mut rng).unwrap(),
+        ] {
+            assert_eq!(3, weight);
+            let side = sorted(side.clone());
+            assert!(side == vec![0, 1] || side == vec![2, 3]);
+        }
+        // the vertex 2 is isolated
+        let g: StaticGraph = graph!(3, (0, 1));
+        let w = g.default_edge_prop(1u32);
+        for &(weight, ref side) in &[
+            g.stoer_wagner(&w).unwrap(),
+            g.karger_stein(&w, &mut rng).unwrap(),
+        ] {
+            assert_eq!(0, weight);
+            let side = sorted(side.clone());
+            assert!(side == vec![2] || side == vec![0, 1]);
+        }
+        let g: StaticGraph = graph!(1);
+        assert_eq!(None, g.stoer_wagner(g.default_edge_prop(1u32)));
+    }
+}
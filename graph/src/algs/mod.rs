@@ -4,24 +4,54 @@
 
 //! Collection of algorithms.
 
+pub mod arborescence;
+pub mod bipartite;
 pub mod boruvka;
+pub mod coloring;
 pub mod components;
+pub mod connectivity;
 pub mod cycles;
 pub mod degrees;
 pub mod distances;
+pub mod eulerian;
+pub mod flow;
+pub mod gomory_hu;
 pub mod kruskal;
+pub mod matching;
+pub mod min_cut;
+pub mod mst;
 pub mod paths;
 pub mod prim;
 pub mod sets;
+pub mod shortest_paths;
+pub mod spanning_trees;
+pub mod steiner;
 pub mod trees;
+pub mod tsp;
 
+mod index;
+mod queue;
+
+pub use self::arborescence::Arborescence;
+pub use self::bipartite::Bipartite;
 pub use self::boruvka::Boruvka;
+pub use self::coloring::Coloring;
 pub use self::components::Components;
+pub use self::connectivity::Connectivity;
 pub use self::cycles::Cycles;
 pub use self::degrees::Degrees;
 pub use self::distances::Distances;
+pub use self::eulerian::Eulerian;
+pub use self::flow::{MaxFlow, MinCostFlow};
+pub use self::gomory_hu::GomoryHu;
 pub use self::kruskal::Kruskal;
+pub use self::matching::Matching;
+pub use self::min_cut::MinCut;
 pub use self::paths::Paths;
 pub use self::prim::Prim;
 pub use self::sets::Sets;
+pub use self::shortest_paths::ShortestPaths;
+pub use self::spanning_trees::SpanningTrees;
+pub use self::steiner::Steiner;
 pub use self::trees::Trees;
+pub use self::tsp::Tsp;
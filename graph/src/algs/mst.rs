@@ -0,0 +1,83 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Common interface of the minimum spanning tree algorithms.
+//!
+//! The algorithms [`Kruskal`], [`Prim`] and [`Boruvka`] share the same interface:
+//!
+//! - They return a builder that implements `IntoIterator`, so the edges of the tree are
+//!   produced lazily;
+//! - The edges can be rejected with a [`Visitor`] set with the `visitor` method (use
+//!   [`KruskalAlg::mst_visitor`] for Kruskal), a rejected edge is never considered again and the
+//!   algorithm continues as if the edge was not in the graph;
+//! - A minimum spanning forest is produced if the graph is disconnected. `PrimAlg::root` can be
+//!   used to produce only the tree of the component of a given vertex.
+//!
+//! # Example
+//!
+//! ```
+//! #[macro_use]
+//! extern crate fera_graph;
+//!
+//! use fera_graph::prelude::*;
+//! use fera_graph::algs::{Boruvka, Kruskal, Prim};
+//! use fera_graph::sum_prop;
+//!
+//! # fn main() {
+//! // two components
+//! let g: StaticGraph = graph!(5, (0, 1), (1, 2), (2, 0), (3, 4));
+//! let mut w = g.default_edge_prop(0u32);
+//! for (e, &x) in g.edges().zip(&[1, 2, 3, 4]) {
+//!     w[e] = x;
+//! }
+//!
+//! let forest: Vec<_> = g.prim(&w).into_iter().collect();
+//! assert_eq!(7u32, sum_prop(&w, &forest));
+//!
+//! // do not use the edge (1, 2)
+//! let e: Vec<_> = g.edges().collect();
+//! let forest = g.boruvka(&w).visitor(|_: &StaticGraph, f| f != e[1]).run();
+//! assert_eq!(8u32, sum_prop(&w, &forest));
+//!
+//! let forest: Vec<_> = g
+//!     .kruskal_mst(&w)
+//!     .mst_visitor(|_: &StaticGraph, f| f != e[1])
+//!     .into_iter()
+//!     .collect();
+//! assert_eq!(8u32, sum_prop(&w, &forest));
+//! # }
+//! ```
+//!
+//! [`Kruskal`]: ../kruskal/trait.Kruskal.html
+//! [`Prim`]: ../prim/trait.Prim.html
+//! [`Boruvka`]: ../boruvka/trait.Boruvka.html
+//! [`Visitor`]: trait.Visitor.html
+//! [`KruskalAlg::mst_visitor`]: ../kruskal/struct.KruskalAlg.html#method.mst_visitor
+
+use prelude::*;
+
+/// A visitor that decides which edges can be used by a minimum spanning tree algorithm.
+pub trait Visitor<G: WithEdge> {
+    /// Returns `true` if the edge `e`, which would be added to the tree, can be used.
+    fn accept(&mut self, g: &G, e: Edge<G>) -> bool;
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AcceptAll;
+
+impl<G: WithEdge> Visitor<G> for AcceptAll {
+    fn accept(&mut self, _g: &G, _e: Edge<G>) -> bool {
+        true
+    }
+}
+
+impl<F, G> Visitor<G> for F
+where
+    G: WithEdge,
+    F: FnMut(&G, Edge<G>) -> bool,
+{
+    fn accept(&mut self, g: &G, e: Edge<G>) -> bool {
+        self(g, e)
+    }
+}
@@ -6,26 +6,33 @@
 //!
 //! [Prim]: https://en.wikipedia.org/wiki/Prim's_algorithm
 
+use algs::mst::{AcceptAll, Visitor};
+pub use algs::queue::QueueItem;
 use params::*;
 use prelude::*;
 use props::Color;
 
-use fera_fun::first;
-
-use std::cmp::Ordering;
 use std::collections::BinaryHeap;
+use std::iter;
 use std::marker::PhantomData;
 use std::ops::DerefMut;
 
 pub trait Prim: Incidence {
+    /// Returns a builder for the Prim's algorithm that produces the edges of a minimum spanning
+    /// forest. Use [`PrimAlg::root`](struct.PrimAlg.html#method.root) to produce only the tree of
+    /// the component of a vertex.
+    ///
+    /// See the [`mst`](../mst/index.html) module for the interface shared by all minimum spanning
+    /// tree algorithms.
     fn prim<W, T>(
         &self,
         w: W,
     ) -> PrimAlg<
         &Self,
         W,
+        AcceptAll,
+        AllVertices<Self>,
         NewVertexProp<Self, Color>,
-        NewVertexProp<Self, OptionEdge<Self>>,
         Owned<PrimPriorityQueue<Self, T>>,
         PhantomData<T>,
     >
@@ -36,8 +43,9 @@ pub trait Prim: Incidence {
         PrimAlg(
             self,
             w,
+            AcceptAll,
+            AllVertices(self),
             NewVertexProp(self, Color::White),
-            NewVertexProp(self, Self::edge_none()),
             Owned(PrimPriorityQueue::<Self, T>::new()),
             PhantomData,
         )
@@ -48,126 +56,111 @@ impl<G: Incidence> Prim for G {}
 
 generic_struct! {
     #[must_use]
-    pub struct PrimAlg(graph, weight, color, parent, queue, _marker)
+    pub struct PrimAlg(graph, weight, visitor, roots, color, queue, _marker)
+}
+
+impl<'a, G, W, V, R, C, Q, T> PrimAlg<&'a G, W, V, R, C, Q, T> {
+    pub fn root(self, root: Vertex<G>) -> PrimAlg<&'a G, W, V, iter::Once<Vertex<G>>, C, Q, T>
+    where
+        G: WithVertex,
+    {
+        self.roots(iter::once(root))
+    }
 }
 
-impl<'a, G, W, C, P, Q, T> IntoIterator for PrimAlg<&'a G, W, C, P, Q, PhantomData<T>>
+impl<'a, G, W, V, R, C, Q, T> IntoIterator for PrimAlg<&'a G, W, V, R, C, Q, PhantomData<T>>
 where
-    G: Incidence + VertexList,
+    G: Incidence,
     W: EdgePropGet<G, T>,
+    V: Visitor<G>,
+    R: IntoIterator<Item = Vertex<G>>,
     C: ParamDerefMut,
     C::Target: VertexPropMut<G, Color>,
-    P: ParamDerefMut,
-    P::Target: VertexPropMut<G, OptionEdge<G>>,
     Q: ParamDerefMut<Target = PrimPriorityQueue<G, T>>,
-    T: Ord + Default,
+    T: Ord,
 {
     type Item = Edge<G>;
-    type IntoIter = Iter<'a, G, W, C::Output, P::Output, Q::Output, T>;
+    type IntoIter = Iter<'a, G, W, V, R::IntoIter, C::Output, Q::Output, T>;
 
     fn into_iter(self) -> Self::IntoIter {
-        let PrimAlg(g, w, color, parent, queue, _) = self;
-        let mut color = color.build();
-        let mut queue = queue.build();
-        let v = first(g.vertices());
-        color[v] = Color::Gray;
-        queue.push(QueueItem::new(T::default(), v));
+        let PrimAlg(g, w, visitor, roots, color, queue, _) = self;
         Iter {
             g,
             w,
-            color,
-            queue,
-            parent: parent.build(),
+            visitor,
+            roots: roots.into_iter(),
+            color: color.build(),
+            queue: queue.build(),
             _marker: PhantomData,
         }
     }
 }
 
-pub struct Iter<'a, G, W, C, P, Q, T>
+pub struct Iter<'a, G, W, V, R, C, Q, T>
 where
     G: 'a,
 {
     g: &'a G,
     w: W,
+    visitor: V,
+    roots: R,
     color: C,
-    parent: P,
     queue: Q,
     _marker: PhantomData<T>,
 }
 
-impl<'a, G, W, C, P, Q, T> Iterator for Iter<'a, G, W, C, P, Q, T>
+impl<'a, G, W, V, R, C, Q, T> Iter<'a, G, W, V, R, C, Q, T>
 where
     G: 'a + Incidence,
     W: EdgePropGet<G, T>,
     C: DerefMut,
     C::Target: VertexPropMut<G, Color>,
-    P: DerefMut,
-    P::Target: VertexPropMut<G, OptionEdge<G>>,
+    Q: DerefMut<Target = PrimPriorityQueue<G, T>>,
+    T: Ord,
+{
+    fn add(&mut self, u: Vertex<G>) {
+        self.color[u] = Color::Black;
+        for e in self.g.out_edges(u) {
+            if self.color[self.g.target(e)] != Color::Black {
+                self.queue.push(QueueItem::new(self.w.get(e), e));
+            }
+        }
+    }
+}
+
+impl<'a, G, W, V, R, C, Q, T> Iterator for Iter<'a, G, W, V, R, C, Q, T>
+where
+    G: 'a + Incidence,
+    W: EdgePropGet<G, T>,
+    V: Visitor<G>,
+    R: Iterator<Item = Vertex<G>>,
+    C: DerefMut,
+    C::Target: VertexPropMut<G, Color>,
     Q: DerefMut<Target = PrimPriorityQueue<G, T>>,
     T: Ord,
 {
     type Item = Edge<G>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        while let Some(QueueItem { vertex: u, .. }) = self.queue.pop() {
-            if self.color[u] == Color::Black {
-                continue;
-            }
-            self.color[u] = Color::Black;
-            for e in self.g.out_edges(u) {
+        loop {
+            // the items of the queue are the edges leaving the tree
+            while let Some(QueueItem { item: e, .. }) = self.queue.pop() {
                 let v = self.g.target(e);
-                if self.color[v] == Color::Black {
+                if self.color[v] == Color::Black || !self.visitor.accept(self.g, e) {
                     continue;
                 }
-                if let Some(p) = self.parent[v].into_option() {
-                    if self.w.get(p) < self.w.get(e) {
-                        continue;
-                    }
-                }
-                self.color[v] = Color::Gray;
-                self.parent[v] = e.into();
-                self.queue.push(QueueItem::new(self.w.get(e), v));
-            }
-            if let e @ Some(_) = self.parent[u].into_option() {
-                return e;
+                self.add(v);
+                return Some(e);
             }
+            // start a new tree
+            let color = &self.color;
+            let root = self.roots.by_ref().find(|&v| color[v] != Color::Black)?;
+            self.add(root);
         }
-        None
     }
 }
 
-type PrimPriorityQueue<G, T> = BinaryHeap<QueueItem<T, Vertex<G>>>;
-
-pub struct QueueItem<A, B> {
-    prio: A,
-    vertex: B,
-}
-
-impl<A, B> QueueItem<A, B> {
-    pub fn new(prio: A, vertex: B) -> Self {
-        Self { prio, vertex }
-    }
-}
-
-impl<A: PartialEq, B> PartialEq for QueueItem<A, B> {
-    fn eq(&self, other: &Self) -> bool {
-        self.prio == other.prio
-    }
-}
-
-impl<A: Eq, B> Eq for QueueItem<A, B> {}
-
-impl<A: PartialOrd, B> PartialOrd for QueueItem<A, B> {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        other.prio.partial_cmp(&self.prio)
-    }
-}
-
-impl<A: Ord, B> Ord for QueueItem<A, B> {
-    fn cmp(&self, other: &Self) -> Ordering {
-        other.prio.cmp(&self.prio)
-    }
-}
+type PrimPriorityQueue<G, T> = BinaryHeap<QueueItem<T, Edge<G>>>;
 
 #[cfg(test)]
 mod tests {
@@ -197,4 +190,20 @@ mod tests {
         assert_eq!(11usize, sum_prop(&weight, &tree));
         assert_eq!(vec![e[0], e[2], e[4], e[1]], tree);
     }
+
+    #[test]
+    fn forest() {
+        let g: StaticGraph = graph!(6, (0, 1), (1, 2), (0, 2), (3, 4), (4, 5), (3, 5));
+        let mut weight = g.default_edge_prop(0usize);
+        for (e, &w) in g.edges().zip(&[1, 3, 2, 6, 4, 5]) {
+            weight[e] = w;
+        }
+        let e = vec(g.edges());
+        assert_eq!(vec![e[0], e[2], e[5], e[4]], vec(g.prim(&weight)));
+        assert_eq!(vec![e[4], e[5]], vec(g.prim(&weight).root(4)));
+        let tree = g
+            .prim(&weight)
+            .visitor(|_: &StaticGraph, f| f != e[2] && f != e[4]);
+        assert_eq!(vec![e[0], e[1], e[5], e[3]], vec(tree));
+    }
 }
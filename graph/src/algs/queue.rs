@@ -0,0 +1,38 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::cmp::Ordering;
+
+/// An item of a min priority queue. The items are compared in reverse order of `prio`, so a
+/// `BinaryHeap` of `QueueItem` pops the item with the smallest priority first.
+pub struct QueueItem<A, B> {
+    pub(crate) prio: A,
+    pub(crate) item: B,
+}
+
+impl<A, B> QueueItem<A, B> {
+    pub fn new(prio: A, item: B) -> Self {
+        Self { prio, item }
+    }
+}
+
+impl<A: PartialEq, B> PartialEq for QueueItem<A, B> {
+    fn eq(&self, other: &Self) -> bool {
+        self.prio == other.prio
+    }
+}
+
+impl<A: Eq, B> Eq for QueueItem<A, B> {}
+
+impl<A: PartialOrd, B> PartialOrd for QueueItem<A, B> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        other.prio.partial_cmp(&self.prio)
+    }
+}
+
+impl<A: Ord, B> Ord for QueueItem<A, B> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.prio.cmp(&self.prio)
+    }
+}
@@ -2,8 +2,10 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
-//! Iterators for edge and vertex set complements.
+//! Iterators for edge and vertex set complements, independent sets and cliques.
 
+use algs::index::vertex_index;
+use algs::Coloring;
 use params::IntoOwned;
 use prelude::*;
 
@@ -68,6 +70,93 @@ pub trait Sets {
         }
         true
     }
+
+    /// Returns `true` if every pair of distinct vertices in `vertices` is adjacent. The adjacency
+    /// is tested with `get_edge_by_ends`, so it is fast for graphs like `AdjSetGraph`.
+    fn is_clique<I>(&self, vertices: I) -> bool
+    where
+        Self: AdjacencyGraph,
+        I: IntoIterator,
+        I::Item: IntoOwned<Vertex<Self>>,
+    {
+        let vertices: Vec<_> = vertices.into_iter().map(IntoOwned::into_owned).collect();
+        vertices.iter().enumerate().all(|(i, &u)| {
+            vertices[i + 1..]
+                .iter()
+                .all(|&v| u != v && self.get_edge_by_ends(u, v).is_some())
+        })
+    }
+
+    /// Returns an iterator over the maximal cliques of the graph (loops are ignored). The cliques
+    /// are generated lazily with the Bron-Kerbosch algorithm with pivoting, where the vertices in
+    /// the outer level are considered in degeneracy order.
+    ///
+    /// The adjacency is tested with `get_edge_by_ends`, so it is fast for graphs like
+    /// `AdjSetGraph`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate fera_graph;
+    ///
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::Sets;
+    ///
+    /// # fn main() {
+    /// let g: StaticGraph = graph!(5, (0, 1), (0, 2), (1, 2), (2, 3), (3, 4));
+    /// let mut cliques: Vec<_> = g.maximal_cliques().map(|mut c| { c.sort(); c }).collect();
+    /// cliques.sort();
+    /// assert_eq!(vec![vec![0, 1, 2], vec![2, 3], vec![3, 4]], cliques);
+    /// # }
+    /// ```
+    fn maximal_cliques(&self) -> MaximalCliques<Self>
+    where
+        Self: AdjacencyGraph,
+    {
+        let mut order = self.smallest_last_order();
+        order.reverse();
+        let pos = vertex_index(self, &order);
+        MaximalCliques {
+            g: self,
+            order: order.into_iter(),
+            pos,
+            stack: vec![],
+        }
+    }
+
+    /// Returns a clique with the maximum number of vertices. The clique is found with a branch
+    /// and bound algorithm that uses a greedy coloring of the candidate vertices as an upper
+    /// bound. The running time is exponential, so it should only be used with small or sparse
+    /// graphs.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate fera_graph;
+    ///
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::Sets;
+    ///
+    /// # fn main() {
+    /// let g: StaticGraph = graph!(6, (0, 1), (1, 2), (2, 3), (3, 0), (1, 3), (0, 2), (4, 5));
+    /// let mut clique = g.maximum_clique();
+    /// clique.sort();
+    /// assert_eq!(vec![0, 1, 2, 3], clique);
+    /// # }
+    /// ```
+    fn maximum_clique(&self) -> Vec<Vertex<Self>>
+    where
+        Self: AdjacencyGraph,
+    {
+        let mut search = MaximumClique {
+            g: self,
+            best: vec![],
+        };
+        search.expand(&mut vec![], self.largest_first_order());
+        search.best
+    }
 }
 
 impl<G> Sets for G {}
@@ -150,3 +239,161 @@ where
         None
     }
 }
+
+pub struct MaximalCliques<'a, G>
+where
+    G: 'a + AdjacencyGraph,
+{
+    g: &'a G,
+    order: ::std::vec::IntoIter<Vertex<G>>,
+    pos: DefaultVertexPropMut<G, usize>,
+    stack: Vec<CliqueFrame<G>>,
+}
+
+// A call of the Bron-Kerbosch algorithm: r is the current clique, p has the vertices that can
+// extend r, x has the vertices that could extend r but were already considered, and cand has the
+// vertices of p that are not adjacent to the pivot.
+struct CliqueFrame<G: WithVertex> {
+    r: Vec<Vertex<G>>,
+    p: Vec<Vertex<G>>,
+    x: Vec<Vertex<G>>,
+    cand: Vec<Vertex<G>>,
+}
+
+impl<'a, G> MaximalCliques<'a, G>
+where
+    G: 'a + AdjacencyGraph,
+{
+    fn adjacent(&self, u: Vertex<G>, v: Vertex<G>) -> bool {
+        u != v && self.g.get_edge_by_ends(u, v).is_some()
+    }
+
+    fn frame(&self, r: Vec<Vertex<G>>, p: Vec<Vertex<G>>, x: Vec<Vertex<G>>) -> CliqueFrame<G> {
+        // the pivot is the vertex with more neighbors in p, the vertices adjacent to the pivot
+        // does not need to be tried because a maximal clique contains the pivot or one of its non
+        // neighbors
+        let pivot = p
+            .iter()
+            .chain(&x)
+            .max_by_key(|&&u| p.iter().filter(|&&v| self.adjacent(u, v)).count())
+            .cloned()
+            .unwrap();
+        let mut cand: Vec<_> = p
+            .iter()
+            .cloned()
+            .filter(|&v| !self.adjacent(pivot, v))
+            .collect();
+        cand.reverse();
+        CliqueFrame { r, p, x, cand }
+    }
+}
+
+impl<'a, G> Iterator for MaximalCliques<'a, G>
+where
+    G: 'a + AdjacencyGraph,
+{
+    type Item = Vec<Vertex<G>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (r, p, x) = if let Some(mut frame) = self.stack.pop() {
+                let v = match frame.cand.pop() {
+                    Some(v) => v,
+                    None => continue,
+                };
+                let mut r = frame.r.clone();
+                r.push(v);
+                let p = frame
+                    .p
+                    .iter()
+                    .cloned()
+                    .filter(|&u| self.adjacent(u, v))
+                    .collect();
+                let x = frame
+                    .x
+                    .iter()
+                    .cloned()
+                    .filter(|&u| self.adjacent(u, v))
+                    .collect();
+                // moves v from p to x
+                frame.p.retain(|&u| u != v);
+                frame.x.push(v);
+                self.stack.push(frame);
+                (r, p, x)
+            } else {
+                // starts a new outer level call with the next vertex in degeneracy order
+                let v = self.order.next()?;
+                let (mut p, mut x) = (vec![], vec![]);
+                for u in self.g.out_neighbors(v) {
+                    if u == v || p.contains(&u) || x.contains(&u) {
+                        continue;
+                    }
+                    if self.pos[u] > self.pos[v] {
+                        p.push(u);
+                    } else {
+                        x.push(u);
+                    }
+                }
+                (vec![v], p, x)
+            };
+            if p.is_empty() {
+                if x.is_empty() {
+                    return Some(r);
+                }
+            } else {
+                let frame = self.frame(r, p, x);
+                self.stack.push(frame);
+            }
+        }
+    }
+}
+
+struct MaximumClique<'a, G: 'a + AdjacencyGraph> {
+    g: &'a G,
+    best: Vec<Vertex<G>>,
+}
+
+impl<'a, G: 'a + AdjacencyGraph> MaximumClique<'a, G> {
+    fn adjacent(&self, u: Vertex<G>, v: Vertex<G>) -> bool {
+        u != v && self.g.get_edge_by_ends(u, v).is_some()
+    }
+
+    // Searches the cliques that contain r and vertices of p, which are adjacent to all vertices
+    // of r.
+    fn expand(&mut self, r: &mut Vec<Vertex<G>>, mut p: Vec<Vertex<G>>) {
+        // colors p greedily, a clique has at most one vertex of each color, so the vertices of p
+        // with color less than c can extend r with at most c vertices
+        let mut classes: Vec<Vec<Vertex<G>>> = vec![];
+        for &v in &p {
+            match classes
+                .iter()
+                .position(|class| class.iter().all(|&u| !self.adjacent(u, v)))
+            {
+                Some(i) => classes[i].push(v),
+                None => classes.push(vec![v]),
+            }
+        }
+        let mut order = vec![];
+        for (c, class) in classes.iter().enumerate() {
+            for &v in class {
+                order.push((v, c + 1));
+            }
+        }
+        while let Some((v, bound)) = order.pop() {
+            if r.len() + bound <= self.best.len() {
+                return;
+            }
+            r.push(v);
+            let q: Vec<_> = p.iter().cloned().filter(|&u| self.adjacent(u, v)).collect();
+            if q.is_empty() {
+                if r.len() > self.best.len() {
+                    self.best = r.clone();
+                }
+            } else {
+                self.expand(r, q);
+            }
+            r.pop();
+            p.retain(|&u| u != v);
+        }
+    }
+}
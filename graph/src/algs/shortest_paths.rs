@@ -0,0 +1,563 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Weighted shortest paths algorithms, including [Dijkstra]'s, [Bellman-Ford]'s and [A*]
+//! algorithms.
+//!
+//! [Dijkstra]: https://en.wikipedia.org/wiki/Dijkstra's_algorithm
+//! [Bellman-Ford]: https://en.wikipedia.org/wiki/Bellman–Ford_algorithm
+//! [A*]: https://en.wikipedia.org/wiki/A*_search_algorithm
+
+use algs::queue::QueueItem;
+use params::*;
+use prelude::*;
+
+use num_traits::{Bounded, Zero};
+
+use std::collections::BinaryHeap;
+use std::fmt;
+
+pub trait ShortestPaths: Incidence {
+    /// Creates a Dijkstra's single source shortest paths algorithm that computes the distances
+    /// from `source` to every vertex using the (non negative) edge weights `w`.
+    ///
+    /// After the execution, the distance of a vertex not reachable from `source` is
+    /// `T::max_value()` and its parent edge is `None`. The parent edge of a vertex `v` is the last
+    /// edge of a shortest path from `source` to `v`, that is, its target is `v`.
+    ///
+    /// If a target is set (with `target`), the algorithm stops as soon as the shortest path to
+    /// the target is known.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate fera_graph;
+    ///
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::ShortestPaths;
+    ///
+    /// # fn main() {
+    /// let (g, w): (StaticDigraph, _) = graph!(
+    ///     4,
+    ///     (0, 1) -> 5u32,
+    ///     (0, 2) -> 1,
+    ///     (2, 1) -> 2,
+    ///     (1, 3) -> 1
+    /// );
+    /// let v: Vec<_> = g.vertices().collect();
+    /// let (dist, parent) = g.dijkstra(v[0], &w).run();
+    /// assert_eq!(vec![0, 3, 1, 4], g.vertices().map(|u| dist[u]).collect::<Vec<_>>());
+    /// assert_eq!(Some(v[1]), parent[v[3]].into_option().map(|e| g.source(e)));
+    /// # }
+    /// ```
+    fn dijkstra<W, T>(
+        &self,
+        source: Vertex<Self>,
+        w: W,
+    ) -> DijkstraAlg<
+        &Self,
+        W,
+        Vertex<Self>,
+        OptionVertex<Self>,
+        NewVertexProp<Self, T>,
+        NewVertexProp<Self, OptionEdge<Self>>,
+        Owned<DijkstraQueue<Self, T>>,
+    >
+    where
+        W: EdgePropGet<Self, T>,
+        T: Bounded,
+    {
+        DijkstraAlg(
+            self,
+            w,
+            source,
+            Self::vertex_none(),
+            NewVertexProp(self, T::max_value()),
+            NewVertexProp(self, Self::edge_none()),
+            Owned(DijkstraQueue::<Self, T>::new()),
+        )
+    }
+
+    /// Computes the distances from `source` to every vertex using the Bellman-Ford's algorithm.
+    ///
+    /// Unlike `dijkstra`, the weights `w` can be negative. If a cycle with negative weight is
+    /// reachable from `source`, the edges of one such cycle are returned as an error. Note that
+    /// an undirected edge with negative weight forms a negative cycle by itself.
+    ///
+    /// The distances and parent edges are defined as in `dijkstra`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate fera_graph;
+    ///
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::ShortestPaths;
+    ///
+    /// # fn main() {
+    /// let (g, mut w): (StaticDigraph, _) = graph!(
+    ///     3,
+    ///     (0, 1) -> 4i32,
+    ///     (1, 2) -> -3,
+    ///     (2, 1) -> 4
+    /// );
+    /// let v: Vec<_> = g.vertices().collect();
+    /// let (dist, _) = g.bellman_ford(v[0], &w).unwrap();
+    /// assert_eq!(1, dist[v[2]]);
+    ///
+    /// let e = g.edge_by_ends(v[2], v[1]);
+    /// w[e] = 1;
+    /// let cycle = g.bellman_ford(v[0], &w).unwrap_err();
+    /// assert_eq!(2, cycle.0.len());
+    /// # }
+    /// ```
+    fn bellman_ford<W, T>(
+        &self,
+        source: Vertex<Self>,
+        w: W,
+    ) -> Result<
+        (
+            DefaultVertexPropMut<Self, T>,
+            DefaultVertexPropMut<Self, OptionEdge<Self>>,
+        ),
+        NegativeCycle<Self>,
+    >
+    where
+        Self: VertexList + EdgeList + WithVertexProp<T> + WithVertexProp<OptionEdge<Self>>,
+        W: EdgePropGet<Self, T>,
+        T: Copy + Ord + Zero + Bounded,
+    {
+        let mut dist = self.default_vertex_prop(T::max_value());
+        let mut parent = self.default_vertex_prop(Self::edge_none());
+        dist[source] = T::zero();
+        bellman_ford_rounds(self, &w, &mut dist, &mut parent)?;
+        Ok((dist, parent))
+    }
+
+    /// Finds a shortest path from `source` to `target` using the A* algorithm with the (non
+    /// negative) edge weights `w` and the heuristic `h`.
+    ///
+    /// The heuristic `h[v]` is an estimate of the distance from `v` to `target`. If the heuristic
+    /// never overestimates the distance (that is, it is admissible), the returned path is a
+    /// shortest path. The better the estimate, the fewer vertices are examined. A heuristic that
+    /// is always zero makes the algorithm behave like `dijkstra`. A vertex with heuristic
+    /// `T::max_value()` is assumed to not reach `target` and is never examined.
+    ///
+    /// Returns `None` if there is no path from `source` to `target`. If `source == target` the
+    /// path is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate fera_graph;
+    ///
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::ShortestPaths;
+    /// use fera_graph::props::FnProp;
+    ///
+    /// # fn main() {
+    /// // a 3x3 grid where the vertex v is at row v / 3 and column v % 3
+    /// let g: StaticGraph = graph!(
+    ///     9,
+    ///     (0, 1), (1, 2), (3, 4), (4, 5), (6, 7), (7, 8),
+    ///     (0, 3), (3, 6), (1, 4), (4, 7), (2, 5), (5, 8)
+    /// );
+    /// let v: Vec<_> = g.vertices().collect();
+    /// let pos = |u: Vertex<StaticGraph>| {
+    ///     let i = v.iter().position(|&x| x == u).unwrap() as i32;
+    ///     (i / 3, i % 3)
+    /// };
+    /// let w = g.default_edge_prop(1i32);
+    /// // the manhattan distance to the bottom right corner
+    /// let h = FnProp(|u| {
+    ///     let (r, c) = pos(u);
+    ///     (2 - r) + (2 - c)
+    /// });
+    /// let path = g.astar(v[0], v[8], &w, h).unwrap();
+    /// assert_eq!(4, path.len());
+    /// assert_eq!(v[0], g.source(path[0]));
+    /// assert_eq!(v[8], g.target(path[3]));
+    /// # }
+    /// ```
+    fn astar<W, H, T>(
+        &self,
+        source: Vertex<Self>,
+        target: Vertex<Self>,
+        w: W,
+        h: H,
+    ) -> Option<Vec<Edge<Self>>>
+    where
+        Self: WithVertexProp<T> + WithVertexProp<OptionEdge<Self>>,
+        W: EdgePropGet<Self, T>,
+        H: VertexPropGet<Self, T>,
+        T: Copy + Ord + Zero + Bounded,
+    {
+        let inf = T::max_value();
+        let mut dist = self.default_vertex_prop(inf);
+        let mut parent = self.default_vertex_prop(Self::edge_none());
+        let mut queue = BinaryHeap::new();
+        dist[source] = T::zero();
+        queue.push(QueueItem::new(h.get(source), source));
+        while let Some(QueueItem { prio: f, item: u }) = queue.pop() {
+            let d = dist[u];
+            if d + h.get(u) < f {
+                // u was already expanded with a shorter distance
+                continue;
+            }
+            if u == target {
+                return path_to(self, source, target, |v| parent[v]);
+            }
+            for e in self.out_edges(u) {
+                let v = self.target(e);
+                let hv = h.get(v);
+                if hv == inf {
+                    continue;
+                }
+                let dv = d + w.get(e);
+                if dv < dist[v] {
+                    dist[v] = dv;
+                    parent[v] = e.into();
+                    queue.push(QueueItem::new(dv + hv, v));
+                }
+            }
+        }
+        None
+    }
+}
+
+impl<G: Incidence> ShortestPaths for G {}
+
+generic_struct! {
+    #[must_use = "call .run() to execute the algorithm"]
+    pub struct DijkstraAlg(graph, weight, source, target, distance, parent, queue)
+}
+
+impl<G, W, S, X, D, P, Q> DijkstraAlg<&G, W, S, X, D, P, Q> {
+    pub fn run<T>(self) -> (D::Output, P::Output)
+    where
+        G: Incidence,
+        W: EdgePropGet<G, T>,
+        S: Into<Vertex<G>>,
+        X: Into<OptionVertex<G>>,
+        D: ParamDerefMut,
+        D::Target: VertexPropMut<G, T>,
+        P: ParamDerefMut,
+        P::Target: VertexPropMut<G, OptionEdge<G>>,
+        Q: ParamDerefMut<Target = DijkstraQueue<G, T>>,
+        T: Copy + Ord + Zero,
+    {
+        let DijkstraAlg(g, w, source, target, dist, parent, queue) = self;
+        let source = source.into();
+        let target = target.into().into_option();
+        let mut dist = dist.build();
+        let mut parent = parent.build();
+        let mut queue = queue.build();
+        dist[source] = T::zero();
+        parent[source] = G::edge_none();
+        queue.push(QueueItem::new(T::zero(), source));
+        while let Some(QueueItem { prio: d, item: u }) = queue.pop() {
+            if dist[u] < d {
+                // u was already settled with a shorter distance
+                continue;
+            }
+            if Some(u) == target {
+                break;
+            }
+            for e in g.out_edges(u) {
+                let v = g.target(e);
+                let dv = d + w.get(e);
+                if dv < dist[v] {
+                    dist[v] = dv;
+                    parent[v] = e.into();
+                    queue.push(QueueItem::new(dv, v));
+                }
+            }
+        }
+        (dist, parent)
+    }
+}
+
+pub type DijkstraQueue<G, T> = BinaryHeap<QueueItem<T, Vertex<G>>>;
+
+// Returns the edges of the path from source to target following the parent edges (the last edge
+// of a path to each vertex) or None if the parent chain stops before reaching source.
+pub(crate) fn path_to<G, P>(
+    g: &G,
+    source: Vertex<G>,
+    target: Vertex<G>,
+    mut parent: P,
+) -> Option<Vec<Edge<G>>>
+where
+    G: WithEdge,
+    P: FnMut(Vertex<G>) -> OptionEdge<G>,
+{
+    let mut path = vec![];
+    let mut v = target;
+    while v != source {
+        let e = parent(v).into_option()?;
+        path.push(e);
+        v = g.source(e);
+    }
+    path.reverse();
+    Some(path)
+}
+
+// Executes the Bellman-Ford's relaxation rounds from the vertices with finite initial distance.
+pub(crate) fn bellman_ford_rounds<G, W, T, D, P>(
+    g: &G,
+    w: &W,
+    dist: &mut D,
+    parent: &mut P,
+) -> Result<(), NegativeCycle<G>>
+where
+    G: VertexList + EdgeList,
+    W: EdgePropGet<G, T>,
+    T: Copy + Ord + Zero + Bounded,
+    D: VertexPropMut<G, T>,
+    P: VertexPropMut<G, OptionEdge<G>>,
+{
+    let inf = T::max_value();
+    let n = g.num_vertices();
+    for i in 0..n {
+        let mut last = None;
+        for e in g.edges() {
+            let rev = if g.orientation(e).is_undirected() {
+                g.get_reverse(e)
+            } else {
+                None
+            };
+            for e in Some(e).into_iter().chain(rev) {
+                let (u, v) = g.ends(e);
+                if dist[u] == inf {
+                    continue;
+                }
+                let dv = dist[u] + w.get(e);
+                if dv < dist[v] {
+                    dist[v] = dv;
+                    parent[v] = e.into();
+                    last = Some(v);
+                }
+            }
+        }
+        match last {
+            None => break,
+            Some(v) if i + 1 == n => {
+                // a vertex was relaxed in the n-th round
+                return Err(NegativeCycle(negative_cycle(g, &*parent, v, n)));
+            }
+            _ => (),
+        }
+    }
+    Ok(())
+}
+
+/// A cycle with negative weight found by a shortest paths algorithm.
+///
+/// The edges form a closed walk, that is, the target of each edge is the source of the next one
+/// and the target of the last edge is the source of the first one.
+pub struct NegativeCycle<G: WithEdge>(pub Vec<Edge<G>>);
+
+impl<G: WithEdge> Clone for NegativeCycle<G> {
+    fn clone(&self) -> Self {
+        NegativeCycle(self.0.clone())
+    }
+}
+
+impl<G: WithEdge> PartialEq for NegativeCycle<G> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<G: WithEdge> fmt::Debug for NegativeCycle<G> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("NegativeCycle").field(&self.0).finish()
+    }
+}
+
+fn negative_cycle<G, P>(g: &G, parent: &P, mut v: Vertex<G>, n: usize) -> Vec<Edge<G>>
+where
+    G: WithEdge,
+    P: VertexProp<G, OptionEdge<G>>,
+{
+    let parent_of = |v: Vertex<G>| -> Edge<G> {
+        parent[v]
+            .into_option()
+            .expect("a parent edge for every vertex in the negative cycle")
+    };
+    // walking back n edges from a vertex relaxed in the n-th round ends in the cycle
+    for _ in 0..n {
+        v = g.source(parent_of(v));
+    }
+    let mut cycle = vec![];
+    let mut u = v;
+    loop {
+        let e = parent_of(u);
+        cycle.push(e);
+        u = g.source(e);
+        if u == v {
+            break;
+        }
+    }
+    cycle.reverse();
+    cycle
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use algs::Paths;
+    use fera_fun::vec;
+    use props::FnProp;
+
+    #[test]
+    fn dijkstra() {
+        let (g, w): (StaticGraph, _) = graph!(
+            6,
+            (0, 1) -> 7u32,
+            (0, 2) -> 9,
+            (0, 5) -> 14,
+            (1, 2) -> 10,
+            (1, 3) -> 15,
+            (2, 3) -> 11,
+            (2, 5) -> 2,
+            (3, 4) -> 6,
+            (4, 5) -> 9
+        );
+        let v = vec(g.vertices());
+        let e = vec(g.edges());
+        let (dist, parent) = g.dijkstra(v[0], &w).run();
+        assert_eq!(
+            vec![0, 7, 9, 20, 20, 11],
+            vec(g.vertices().map(|v| dist[v]))
+        );
+        assert_eq!(None, parent[v[0]].into_option());
+        assert_eq!(Some(e[0]), parent[v[1]].into_option());
+        assert_eq!(Some(e[5]), parent[v[3]].into_option());
+        assert_eq!(Some(e[8]), parent[v[4]].into_option());
+        assert_eq!(v[4], g.target(parent[v[4]].into_option().unwrap()));
+    }
+
+    #[test]
+    fn dijkstra_unreachable() {
+        let (g, w): (StaticDigraph, _) = graph!(3, (1, 0) -> 1u8);
+        let (dist, parent) = g.dijkstra(g.vertices().next().unwrap(), &w).run();
+        let inf = u8::max_value();
+        assert_eq!(vec![0, inf, inf], vec(g.vertices().map(|v| dist[v])));
+        assert!(g.vertices().all(|v| parent[v].into_option().is_none()));
+    }
+
+    #[test]
+    fn dijkstra_target() {
+        let (g, w): (StaticDigraph, _) = graph!(
+            4,
+            (0, 1) -> 1u32,
+            (1, 2) -> 1,
+            (2, 3) -> 1
+        );
+        let v = vec(g.vertices());
+        let (dist, _) = g.dijkstra(v[0], &w).target(v[1]).run();
+        assert_eq!(1, dist[v[1]]);
+        // the search stops when 1 is settled, so its out edges are not examined
+        assert_eq!(u32::max_value(), dist[v[2]]);
+        assert_eq!(u32::max_value(), dist[v[3]]);
+    }
+
+    #[test]
+    fn bellman_ford() {
+        let (g, w): (StaticDigraph, _) = graph!(
+            5,
+            (0, 1) -> 6i32,
+            (0, 3) -> 7,
+            (1, 2) -> 5,
+            (1, 3) -> 8,
+            (1, 4) -> -4,
+            (2, 1) -> -2,
+            (3, 2) -> -3,
+            (3, 4) -> 9,
+            (4, 0) -> 2,
+            (4, 2) -> 7
+        );
+        let v = vec(g.vertices());
+        let (dist, parent) = g.bellman_ford(v[0], &w).unwrap();
+        assert_eq!(vec![0, 2, 4, 7, -2], vec(g.vertices().map(|v| dist[v])));
+        let parents = vec(g
+            .vertices()
+            .map(|v| parent[v].into_option().map(|e| g.source(e))));
+        assert_eq!(vec![None, Some(2), Some(3), Some(0), Some(1)], parents);
+    }
+
+    #[test]
+    fn bellman_ford_negative_cycle() {
+        let (g, w): (StaticDigraph, _) = graph!(
+            5,
+            (0, 1) -> 1i32,
+            (1, 2) -> 1,
+            (2, 3) -> -1,
+            (3, 4) -> 1,
+            (3, 1) -> -1
+        );
+        let v = vec(g.vertices());
+        let NegativeCycle(cycle) = g.bellman_ford(v[0], &w).unwrap_err();
+        let mut ends = vec(g.ends(&cycle));
+        ends.sort();
+        assert_eq!(vec![(v[1], v[2]), (v[2], v[3]), (v[3], v[1])], ends);
+        assert!(g.is_walk(&cycle));
+        assert_eq!(g.source(cycle[0]), g.target(*cycle.last().unwrap()));
+
+        // the negative cycle is not reachable
+        assert!(g.bellman_ford(v[4], &w).is_ok());
+    }
+
+    #[test]
+    fn bellman_ford_undirected() {
+        let (g, w): (StaticGraph, _) = graph!(
+            3,
+            (0, 1) -> 2i32,
+            (1, 2) -> -1
+        );
+        let v = vec(g.vertices());
+        let NegativeCycle(cycle) = g.bellman_ford(v[0], &w).unwrap_err();
+        assert_eq!(2, cycle.len());
+        assert!(g.is_walk(&cycle));
+        assert_eq!(g.source(cycle[0]), g.target(cycle[1]));
+        assert_eq!(g.edge_by_ends(v[1], v[2]), cycle[0]);
+    }
+
+    #[test]
+    fn astar() {
+        let (g, w): (StaticDigraph, _) = graph!(
+            6,
+            (0, 1) -> 1u32,
+            (1, 2) -> 1,
+            (2, 3) -> 1,
+            (0, 4) -> 2,
+            (4, 3) -> 2,
+            (3, 0) -> 1
+        );
+        let v = vec(g.vertices());
+        let zero = FnProp(|_| 0);
+        let path = g.astar(v[0], v[3], &w, &zero).unwrap();
+        assert_eq!(
+            vec![
+                g.edge_by_ends(v[0], v[1]),
+                g.edge_by_ends(v[1], v[2]),
+                g.edge_by_ends(v[2], v[3])
+            ],
+            path
+        );
+        // a heuristic that overestimates the distance from 1 and 2 leads to the longer path
+        let h = FnProp(|u| if u == v[1] || u == v[2] { 5 } else { 0 });
+        let path = g.astar(v[0], v[3], &w, &h).unwrap();
+        assert_eq!(
+            vec![g.edge_by_ends(v[0], v[4]), g.edge_by_ends(v[4], v[3])],
+            path
+        );
+        assert_eq!(Some(vec![]), g.astar(v[3], v[3], &w, &zero));
+        assert_eq!(None, g.astar(v[0], v[5], &w, &zero));
+    }
+}
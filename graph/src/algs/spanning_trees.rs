@@ -0,0 +1,403 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Enumeration of spanning trees in increasing weight order and sensitivity analysis of minimum
+//! spanning trees.
+//!
+//! The spanning trees are enumerated with Lawler's partition method instead of the more
+//! elaborated algorithms of Gabow or Katoh, Ibaraki and Mine. After a tree is produced, the
+//! remaining trees are split in up to `n - 1` subsets, and the minimum spanning tree of each subset
+//! is computed by running Kruskal's algorithm again. The edges are sorted only once, so each
+//! produced tree takes `O(n m α(n))` time, where `α` is the inverse Ackermann function. This is
+//! about `n` times slower per tree than the other algorithms, but much simpler.
+
+use algs::index::vertex_index;
+use algs::queue::QueueItem;
+use algs::Kruskal;
+use prelude::*;
+use unionfind::WithUnionFind;
+
+use fera_fun::vec;
+
+use std::collections::BinaryHeap;
+use std::iter::Sum;
+use std::mem;
+
+const NONE: usize = usize::max_value();
+
+pub trait SpanningTrees: WithUnionFind {
+    /// Returns an iterator that produces the spanning trees of the graph in increasing order of
+    /// weight, that is, the first tree is a minimum spanning tree, the second tree is a minimum
+    /// spanning tree among the remaining trees, and so on. Use `take(k)` to get the `k` best
+    /// spanning trees. If the graph is disconnected, no tree is produced.
+    ///
+    /// The trees are enumerated with Lawler's partition method: each produced tree splits the
+    /// remaining trees into subsets defined by edges that must and must not be used, and the
+    /// minimum spanning tree of each subset is computed with Kruskal's algorithm. Sorting the
+    /// edges takes `O(m log m)` time and producing each tree takes `O(n m α(n))` time, see the
+    /// [module documentation](index.html).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate fera_graph;
+    ///
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::SpanningTrees;
+    /// use fera_graph::sum_prop;
+    ///
+    /// # fn main() {
+    /// let g: StaticGraph = graph!(4, (0, 1), (1, 2), (2, 0), (2, 3));
+    /// let mut w = g.default_edge_prop(0u32);
+    /// for (e, &x) in g.edges().zip(&[1, 2, 3, 4]) {
+    ///     w[e] = x;
+    /// }
+    /// let weights: Vec<u32> = g
+    ///     .spanning_trees_by_weight(&w)
+    ///     .map(|tree| sum_prop(&w, tree.edges()))
+    ///     .collect();
+    /// assert_eq!(vec![7, 8, 9], weights);
+    /// # }
+    /// ```
+    fn spanning_trees_by_weight<W, T>(&self, w: W) -> SpanningTreesByWeight<Self, W, T>
+    where
+        W: EdgePropGet<Self, T>,
+        T: Ord + Sum<T>,
+    {
+        let mut sorted = vec(self.edges());
+        sorted.sort_by_key(|&e| w.get(e));
+        let mut iter = SpanningTreesByWeight {
+            g: self,
+            w,
+            sorted,
+            excluded: self.default_edge_prop(false),
+            queue: BinaryHeap::new(),
+        };
+        iter.push(vec![], vec![]);
+        iter
+    }
+
+    /// Returns the weight range of each edge for which `tree` remains a minimum spanning tree
+    /// (or forest) when only the weight of the edge is changed.
+    ///
+    /// For an edge `e` in `tree`, the range has no lower bound and the upper bound is the minimum
+    /// weight of the edges that can replace `e`, or no upper bound if `e` is a bridge. For an edge
+    /// `f` not in `tree`, the range has no upper bound and the lower bound is the maximum weight
+    /// of the edges in the path of `tree` between the ends of `f`, or no lower bound if `f` is a
+    /// loop.
+    ///
+    /// The running time is `O(m log m)`. The result is unspecified if `tree` is not a minimum
+    /// spanning forest of the graph.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate fera_graph;
+    ///
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::{Kruskal, SpanningTrees};
+    /// use fera_graph::algs::spanning_trees::WeightRange;
+    ///
+    /// # fn main() {
+    /// let g: StaticGraph = graph!(4, (0, 1), (1, 2), (2, 0), (2, 3));
+    /// let mut w = g.default_edge_prop(0u32);
+    /// for (e, &x) in g.edges().zip(&[1, 2, 3, 4]) {
+    ///     w[e] = x;
+    /// }
+    /// let e: Vec<_> = g.edges().collect();
+    /// let tree: Vec<_> = g.kruskal_mst(&w).into_iter().collect();
+    /// let range = g.spanning_tree_sensitivity(&w, &tree);
+    /// assert_eq!(WeightRange { lower: None, upper: Some(3) }, range[e[0]]);
+    /// assert_eq!(WeightRange { lower: Some(2), upper: None }, range[e[2]]);
+    /// assert_eq!(WeightRange { lower: None, upper: None }, range[e[3]]);
+    /// # }
+    /// ```
+    fn spanning_tree_sensitivity<W, T>(
+        &self,
+        w: W,
+        tree: &[Edge<Self>],
+    ) -> DefaultEdgePropMut<Self, WeightRange<T>>
+    where
+        Self: WithEdgeProp<WeightRange<T>>,
+        W: EdgePropGet<Self, T>,
+        T: Ord + Clone,
+    {
+        let n = self.num_vertices();
+        let mut range = self.default_edge_prop(WeightRange {
+            lower: None,
+            upper: None,
+        });
+        let index = vertex_index(self, self.vertices());
+        let mut in_tree = self.default_edge_prop(false);
+        let mut adj = vec![vec![]; n];
+        for (i, &e) in tree.iter().enumerate() {
+            let (u, v) = self.ends(e);
+            let (u, v) = (index[u], index[v]);
+            in_tree[e] = true;
+            adj[u].push((v, i));
+            adj[v].push((u, i));
+        }
+        let mut non_tree: Vec<_> = self
+            .edges_with_ends()
+            .filter(|&(e, _, _)| !in_tree[e])
+            .map(|(e, u, v)| (e, index[u], index[v]))
+            .collect();
+
+        // roots each tree of the forest
+        let mut parent = vec![NONE; n];
+        let mut parent_edge = vec![NONE; n];
+        let mut depth = vec![0; n];
+        let mut stack = vec![];
+        for r in 0..n {
+            if parent[r] != NONE {
+                continue;
+            }
+            parent[r] = r;
+            stack.push(r);
+            while let Some(u) = stack.pop() {
+                for &(v, i) in &adj[u] {
+                    if parent[v] == NONE {
+                        parent[v] = u;
+                        parent_edge[v] = i;
+                        depth[v] = depth[u] + 1;
+                        stack.push(v);
+                    }
+                }
+            }
+        }
+
+        // The upper bound of a tree edge is the weight of the first non tree edge (in increasing
+        // weight order) that covers it. Each tree edge is assigned once, the assigned edges are
+        // skipped with an union find like structure (top[v] is the highest ancestor of v that
+        // can be reached using assigned edges).
+        non_tree.sort_by_key(|&(e, _, _)| w.get(e));
+        let mut top: Vec<_> = (0..n).collect();
+        for &(f, u, v) in &non_tree {
+            let mut a = find(&mut top, u);
+            let mut b = find(&mut top, v);
+            while a != b {
+                if depth[a] < depth[b] {
+                    mem::swap(&mut a, &mut b);
+                }
+                if depth[a] == 0 {
+                    // the ends are in different trees
+                    break;
+                }
+                range[tree[parent_edge[a]]].upper = Some(w.get(f));
+                top[a] = parent[a];
+                a = find(&mut top, a);
+            }
+        }
+
+        // The lower bound of a non tree edge is the weight of the tree edge (in increasing weight
+        // order) that connects its ends. The components are merged as in Kruskal's algorithm and
+        // each component keeps the non tree edges incident to it, the smaller lists are always
+        // merged into the larger ones.
+        let mut comp: Vec<_> = (0..n).collect();
+        let mut members: Vec<_> = (0..n).map(|v| vec![v]).collect();
+        let mut pending = vec![vec![]; n];
+        for (i, &(_, u, v)) in non_tree.iter().enumerate() {
+            if u != v {
+                pending[u].push(i);
+                pending[v].push(i);
+            }
+        }
+        let mut done = vec![false; non_tree.len()];
+        let mut sorted = tree.to_vec();
+        sorted.sort_by_key(|&e| w.get(e));
+        for e in sorted {
+            let (u, v) = self.ends(e);
+            let (mut x, mut y) = (comp[index[u]], comp[index[v]]);
+            if pending[x].len() > pending[y].len() {
+                mem::swap(&mut x, &mut y);
+            }
+            for i in mem::take(&mut pending[x]) {
+                if done[i] {
+                    continue;
+                }
+                let (f, a, b) = non_tree[i];
+                let (a, b) = (comp[a], comp[b]);
+                if a == x && b == y || a == y && b == x {
+                    range[f].lower = Some(w.get(e));
+                    done[i] = true;
+                } else {
+                    pending[y].push(i);
+                }
+            }
+            let (small, large) = if members[x].len() < members[y].len() {
+                (x, y)
+            } else {
+                (y, x)
+            };
+            for v in mem::take(&mut members[small]) {
+                comp[v] = large;
+                members[large].push(v);
+            }
+            if large == x {
+                pending.swap(x, y);
+            }
+        }
+        range
+    }
+}
+
+impl<G: WithUnionFind> SpanningTrees for G {}
+
+fn find(top: &mut [usize], mut v: usize) -> usize {
+    while top[v] != v {
+        top[v] = top[top[v]];
+        v = top[v];
+    }
+    v
+}
+
+/// The range of values of a weight. `None` means that the range is unbounded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WeightRange<T> {
+    pub lower: Option<T>,
+    pub upper: Option<T>,
+}
+
+pub struct SpanningTreesByWeight<'a, G, W, T>
+where
+    G: 'a + WithUnionFind,
+{
+    g: &'a G,
+    w: W,
+    sorted: Vec<Edge<G>>,
+    excluded: DefaultEdgePropMut<G, bool>,
+    // the minimum spanning tree of each subset of trees, the tree is formed by the edges that
+    // must be used (the first ones) followed by the other edges
+    queue: BinaryHeap<QueueItem<T, Subset<G>>>,
+}
+
+struct Subset<G: WithEdge> {
+    included: usize,
+    excluded: Vec<Edge<G>>,
+    tree: Vec<Edge<G>>,
+}
+
+impl<'a, G, W, T> SpanningTreesByWeight<'a, G, W, T>
+where
+    G: 'a + WithUnionFind,
+    W: EdgePropGet<G, T>,
+    T: Ord + Sum<T>,
+{
+    // Computes the minimum spanning tree that contains all `included` edges and none of the
+    // `excluded` edges and adds it to the queue.
+    fn push(&mut self, included: Vec<Edge<G>>, excluded: Vec<Edge<G>>) {
+        let n = self.g.num_vertices();
+        if n == 0 {
+            // kruskal does not work with the null graph
+            let tree = vec![];
+            self.queue.push(QueueItem::new(
+                tree.iter().map(|&e| self.w.get(e)).sum(),
+                Subset {
+                    included: 0,
+                    excluded,
+                    tree,
+                },
+            ));
+            return;
+        }
+        for &e in &excluded {
+            self.excluded[e] = true;
+        }
+        let tree = {
+            let excluded = &self.excluded;
+            let edges = included
+                .iter()
+                .chain(self.sorted.iter().filter(|&&e| !excluded[e]));
+            vec(self.g.kruskal().edges(edges))
+        };
+        for &e in &excluded {
+            self.excluded[e] = false;
+        }
+        if tree.len() == n - 1 {
+            let weight = tree.iter().map(|&e| self.w.get(e)).sum();
+            self.queue.push(QueueItem::new(
+                weight,
+                Subset {
+                    included: included.len(),
+                    excluded,
+                    tree,
+                },
+            ));
+        }
+    }
+}
+
+impl<'a, G, W, T> Iterator for SpanningTreesByWeight<'a, G, W, T>
+where
+    G: 'a + WithUnionFind,
+    W: EdgePropGet<G, T>,
+    T: Ord + Sum<T>,
+{
+    type Item = SpanningSubgraph<'a, G>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Subset {
+            included,
+            excluded,
+            tree,
+        } = self.queue.pop()?.item;
+        // the remaining trees of the subset are partitioned by the first edge of the tree (that
+        // is not included) that is not used
+        for i in included..tree.len() {
+            let mut excluded = excluded.clone();
+            excluded.push(tree[i]);
+            self.push(tree[..i].to_vec(), excluded);
+        }
+        Some(self.g.spanning_subgraph(tree))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fun::sum_prop;
+
+    #[test]
+    fn spanning_trees_by_weight() {
+        let g: StaticGraph = graph!(4, (0, 1), (1, 2), (2, 3), (3, 0), (0, 2));
+        let mut w = g.default_edge_prop(0u32);
+        for (e, &x) in g.edges().zip(&[1, 1, 2, 3, 2]) {
+            w[e] = x;
+        }
+        let weights: Vec<u32> = g
+            .spanning_trees_by_weight(&w)
+            .map(|tree| sum_prop(&w, tree.edges()))
+            .collect();
+        // 8 spanning trees
+        assert_eq!(vec![4, 5, 5, 5, 6, 6, 6, 6], weights);
+
+        let g: StaticGraph = graph!(3, (0, 1));
+        let w = g.default_edge_prop(0u32);
+        assert_eq!(0, g.spanning_trees_by_weight(&w).count());
+    }
+
+    #[test]
+    fn spanning_tree_sensitivity() {
+        let g: StaticGraph = graph!(5, (0, 1), (1, 2), (0, 3), (0, 4), (2, 4), (3, 4));
+        let mut w = g.default_edge_prop(0i32);
+        for (e, &x) in g.edges().zip(&[1, 2, 4, 5, 6, 7]) {
+            w[e] = x;
+        }
+        let e: Vec<_> = g.edges().collect();
+        let tree: Vec<_> = g.kruskal_mst(&w).into_iter().collect();
+        assert_eq!(vec![e[0], e[1], e[2], e[3]], tree);
+        let range = g.spanning_tree_sensitivity(&w, &tree);
+        let upper = |i: usize| range[e[i]].upper;
+        let lower = |i: usize| range[e[i]].lower;
+        assert_eq!(
+            vec![Some(6), Some(6), Some(7), Some(6)],
+            vec![upper(0), upper(1), upper(2), upper(3)]
+        );
+        assert_eq!(vec![Some(5), Some(5)], vec![lower(4), lower(5)]);
+        assert!((0..4).all(|i| lower(i).is_none()));
+        assert!((4..6).all(|i| upper(i).is_none()));
+    }
+}
@@ -0,0 +1,176 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Approximation for the [Steiner tree] problem.
+//!
+//! [Steiner tree]: https://en.wikipedia.org/wiki/Steiner_tree_problem_in_graphs
+
+use algs::{Kruskal, ShortestPaths};
+use prelude::*;
+use props::FnProp;
+use unionfind::WithUnionFind;
+
+use num_traits::{Bounded, Zero};
+
+pub trait Steiner: WithUnionFind + Incidence {
+    /// Finds a tree that connects the `terminals` using the edge weights `w` (which must be non
+    /// negative). The tree has at most twice the weight of a minimum Steiner tree.
+    ///
+    /// The algorithm (by Kou, Markowsky and Berman) finds a minimum spanning tree of the metric
+    /// closure of the terminals with [`Kruskal`], replaces each edge of this tree with a shortest
+    /// path in the graph, finds a minimum spanning tree of the union of the paths and removes
+    /// the leaves that are not terminals.
+    ///
+    /// If the terminals are not all in the same component, the returned forest connects the
+    /// terminals in each component.
+    ///
+    /// The running time is `O(k (m + n) log n + k^2 log k)`, where `k` is the number of
+    /// terminals.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate fera_graph;
+    ///
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::{Components, Cycles, Steiner};
+    /// use fera_graph::sum_prop;
+    ///
+    /// # fn main() {
+    /// // the terminals 0, 1 and 2 are connected through the center 3
+    /// let (g, w): (StaticGraph, _) = graph!(
+    ///     5,
+    ///     (0, 1) -> 5u32,
+    ///     (1, 2) -> 5,
+    ///     (2, 0) -> 5,
+    ///     (0, 3) -> 2,
+    ///     (1, 3) -> 2,
+    ///     (2, 3) -> 2,
+    ///     (3, 4) -> 1
+    /// );
+    /// let v: Vec<_> = g.vertices().collect();
+    /// let tree = g.steiner_tree(vec![v[0], v[1], v[2]], &w);
+    /// assert!(tree.is_acyclic());
+    /// let components = tree.connected_components();
+    /// assert!(components.is_connected(v[0], v[1]) && components.is_connected(v[0], v[2]));
+    /// assert_eq!(3, tree.num_edges());
+    /// assert_eq!(6u32, sum_prop(&w, tree.edges()));
+    /// # }
+    /// ```
+    ///
+    /// [`Kruskal`]: ../kruskal/trait.Kruskal.html
+    fn steiner_tree<W, T>(&self, terminals: Vec<Vertex<Self>>, w: W) -> SpanningSubgraph<Self>
+    where
+        Self: WithVertexProp<T>,
+        W: EdgePropGet<Self, T>,
+        T: Copy + Ord + Zero + Bounded,
+    {
+        let mut is_terminal = self.default_vertex_prop(false);
+        let mut unique = vec![];
+        for v in terminals {
+            if !is_terminal[v] {
+                is_terminal[v] = true;
+                unique.push(v);
+            }
+        }
+        let terminals = unique;
+        if terminals.len() < 2 {
+            return self.empty_spanning_subgraph();
+        }
+
+        // the metric closure of the terminals
+        let paths: Vec<_> = terminals
+            .iter()
+            .map(|&t| self.dijkstra(t, &w).run())
+            .collect();
+        let c = CompleteGraph::new(terminals.len() as u32);
+        let cw = FnProp(|e| {
+            let (i, j) = c.ends(e);
+            paths[i as usize].0[terminals[j as usize]]
+        });
+        let closure = c
+            .kruskal_mst(cw)
+            .mst_visitor(|_: &CompleteGraph, e| cw.get(e) != T::max_value());
+
+        // the union of the shortest paths of the closure tree
+        let mut used = self.default_edge_prop(false);
+        for e in closure {
+            let (i, j) = c.ends(e);
+            let parent = &paths[i as usize].1;
+            let mut v = terminals[j as usize];
+            while let Some(e) = parent[v].into_option() {
+                used[e] = true;
+                v = self.source(e);
+            }
+        }
+        let mut edges: Vec<_> = self.edges().filter(|&e| used[e]).collect();
+        edges.sort_by_key(|&e| w.get(e));
+        let tree = self.spanning_subgraph(self.kruskal().edges(edges));
+
+        // removes the leaves that are not terminals
+        let mut degree = self.default_vertex_prop(0usize);
+        let mut removed = self.default_edge_prop(false);
+        let mut leaves = vec![];
+        for v in tree.vertices() {
+            degree[v] = tree.out_degree(v);
+            if degree[v] == 1 && !is_terminal[v] {
+                leaves.push(v);
+            }
+        }
+        while let Some(v) = leaves.pop() {
+            let e = match tree.out_edges(v).find(|&e| !removed[e]) {
+                Some(e) => e,
+                None => continue,
+            };
+            removed[e] = true;
+            let u = tree.target(e);
+            degree[u] -= 1;
+            if degree[u] == 1 && !is_terminal[u] {
+                leaves.push(u);
+            }
+        }
+        self.spanning_subgraph(tree.edges().filter(|&e| !removed[e]))
+    }
+}
+
+impl<G: WithUnionFind + Incidence> Steiner for G {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use algs::{Components, Cycles};
+    use fera_fun::vec;
+    use fun::sum_prop;
+
+    #[test]
+    fn steiner_tree() {
+        // the shortest paths from 0 to 1 and from 0 to 2 share the edge (0, 3), the vertex 5 is
+        // a leaf that is not a terminal
+        let (g, w): (StaticGraph, _) = graph!(
+            7,
+            (0, 3) -> 1u32,
+            (3, 1) -> 1,
+            (3, 2) -> 1,
+            (0, 1) -> 3,
+            (1, 2) -> 3,
+            (2, 5) -> 1,
+            (4, 6) -> 1
+        );
+        let v = vec(g.vertices());
+        let tree = g.steiner_tree(vec![v[0], v[1], v[2], v[1]], &w);
+        assert!(tree.is_acyclic());
+        assert_eq!(3, tree.num_edges());
+        assert_eq!(3u32, sum_prop(&w, tree.edges()));
+        assert_eq!(0, tree.out_degree(v[5]));
+
+        // 4 and 6 are in another component
+        let forest = g.steiner_tree(vec![v[0], v[1], v[4], v[6]], &w);
+        let components = forest.connected_components();
+        assert!(components.is_connected(v[0], v[1]) && components.is_connected(v[4], v[6]));
+        assert_eq!(2u32 + 1, sum_prop(&w, forest.edges()));
+
+        assert_eq!(0, g.steiner_tree(vec![v[3]], &w).num_edges());
+    }
+}
@@ -0,0 +1,351 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Heuristics for the [travelling salesman problem].
+//!
+//! The graph must be complete, like a [`CompleteGraph`], that is, there must be an edge between
+//! each pair of vertices. A tour is a sequence with each vertex of the graph exactly once, the last
+//! vertex of the sequence is connected to the first one. The tours are returned with their total
+//! cost.
+//!
+//! The distances are read to a matrix at the beginning of each algorithm, so the running times
+//! below do not include the `O(n^2 + m)` time to build the matrix.
+//!
+//! # Example
+//!
+//! ```
+//! use fera_graph::prelude::*;
+//! use fera_graph::algs::Tsp;
+//!
+//! // points in the plane with manhattan distances
+//! let points = [(0i32, 0i32), (3, 0), (0, 3), (3, 3), (1, 1), (2, 2)];
+//! let g = CompleteGraph::new(points.len() as u32);
+//! let w: DefaultEdgePropMut<CompleteGraph, i32> = g.edge_prop_from_fn(|e| {
+//!     let ((x1, y1), (x2, y2)) = (points[g.source(e) as usize], points[g.target(e) as usize]);
+//!     (x1 - x2).abs() + (y1 - y2).abs()
+//! });
+//!
+//! let (tour, cost) = g.christofides_tour(&w);
+//! assert_eq!(points.len(), tour.len());
+//! let (tour, improved) = g.two_opt(&w, tour);
+//! assert!(improved <= cost);
+//! let (_, improved) = g.or_opt(&w, tour);
+//! assert_eq!(16, improved);
+//! ```
+//!
+//! [travelling salesman problem]: https://en.wikipedia.org/wiki/Travelling_salesman_problem
+//! [`CompleteGraph`]: ../../graphs/complete/type.CompleteGraph.html
+
+use algs::index::vertex_index;
+use algs::{Eulerian, Matching, Prim};
+use prelude::*;
+use props::FnProp;
+
+use num_traits::{One, Zero};
+
+use std::ops::{Div, Sub};
+
+pub trait Tsp: Incidence {
+    /// Builds a tour that starts at the first vertex and goes to the nearest unvisited vertex
+    /// until all vertices are visited.
+    ///
+    /// The running time is `O(n^2)`.
+    ///
+    /// # Panics
+    ///
+    /// If the graph is not complete.
+    fn nearest_neighbor_tour<W, T>(&self, w: W) -> (Vec<Vertex<Self>>, T)
+    where
+        Self: IncidenceGraph,
+        W: EdgePropGet<Self, T>,
+        T: Copy + Ord + Zero,
+    {
+        let (vertices, _, d) = distances(self, &w);
+        let n = vertices.len();
+        let mut tour = Vec::with_capacity(n);
+        let mut visited = vec![false; n];
+        if n != 0 {
+            tour.push(0);
+            visited[0] = true;
+        }
+        while tour.len() < n {
+            let u = tour[tour.len() - 1];
+            let v = (0..n)
+                .filter(|&v| !visited[v])
+                .min_by_key(|&v| d[u][v])
+                .unwrap();
+            visited[v] = true;
+            tour.push(v);
+        }
+        into_tour(&vertices, &d, &tour)
+    }
+
+    /// Builds a tour using the Christofides' algorithm. A minimum spanning tree is found with
+    /// [`Prim`] and a minimum weight perfect matching of the odd degree vertices of the tree is
+    /// found with [`Matching::min_weight_perfect_matching`]. The tour visits the vertices in the
+    /// order of an Eulerian circuit of the tree with the matching, skipping the visited vertices.
+    ///
+    /// If the distances satisfy the triangle inequality, the cost of the tour is at most `3/2`
+    /// the cost of an optimal tour.
+    ///
+    /// The running time is `O(n^3)`.
+    ///
+    /// # Panics
+    ///
+    /// If the graph is not complete.
+    ///
+    /// [`Prim`]: ../prim/trait.Prim.html
+    /// [`Matching::min_weight_perfect_matching`]:
+    /// ../matching/trait.Matching.html#method.min_weight_perfect_matching
+    fn christofides_tour<W, T>(&self, w: W) -> (Vec<Vertex<Self>>, T)
+    where
+        Self: IncidenceGraph,
+        W: EdgePropGet<Self, T>,
+        T: Copy + Ord + Zero + One + Sub<Output = T> + Div<Output = T>,
+    {
+        let (vertices, index, d) = distances(self, &w);
+        let n = vertices.len();
+        let mut degree = vec![0; n];
+        let mut edges = vec![];
+        for e in self.prim(&w) {
+            let (u, v) = self.ends(e);
+            let (u, v) = (index[u], index[v]);
+            degree[u] += 1;
+            degree[v] += 1;
+            edges.push((u, v));
+        }
+        let odd: Vec<_> = (0..n).filter(|&v| degree[v] % 2 == 1).collect();
+        let c = CompleteGraph::new(odd.len() as u32);
+        let cw = FnProp(|e| {
+            let (a, b) = c.ends(e);
+            d[odd[a as usize]][odd[b as usize]]
+        });
+        let (matching, _) = c
+            .min_weight_perfect_matching(cw)
+            .expect("a perfect matching of the odd degree vertices");
+        edges.extend(matching.into_iter().map(|e| {
+            let (a, b) = c.ends(e);
+            (odd[a as usize], odd[b as usize])
+        }));
+        let h = StaticGraph::new_with_edges(n, edges);
+        let circuit = h
+            .eulerian_circuit()
+            .expect("an Eulerian circuit of the tree with the matching");
+        let mut visited = vec![false; n];
+        let mut tour = Vec::with_capacity(n);
+        for u in circuit
+            .into_iter()
+            .map(|e| h.source(e) as usize)
+            .chain(0..n)
+        {
+            if !visited[u] {
+                visited[u] = true;
+                tour.push(u);
+            }
+        }
+        into_tour(&vertices, &d, &tour)
+    }
+
+    /// Improves `tour` with the 2-opt local search. While there are two edges `(a, b)` and `(c,
+    /// d)` of the tour such that replacing them with the edges `(a, c)` and `(b, d)` decreases the
+    /// cost of the tour, the path from `b` to `c` is reversed.
+    ///
+    /// Each pass over the pairs of edges takes `O(n^2)` time.
+    ///
+    /// # Panics
+    ///
+    /// If the graph is not complete or if `tour` is not a tour.
+    fn two_opt<W, T>(&self, w: W, tour: Vec<Vertex<Self>>) -> (Vec<Vertex<Self>>, T)
+    where
+        Self: IncidenceGraph,
+        W: EdgePropGet<Self, T>,
+        T: Copy + Ord + Zero,
+    {
+        let (vertices, index, d) = distances(self, &w);
+        let mut tour = from_tour::<Self>(&index, vertices.len(), &tour);
+        let n = tour.len();
+        let mut improved = true;
+        while improved {
+            improved = false;
+            for i in 0..n.saturating_sub(2) {
+                // when i == 0 the edge (tour[n - 1], tour[0]) is adjacent to (tour[0], tour[1])
+                let end = if i == 0 { n - 1 } else { n };
+                for j in i + 2..end {
+                    let (a, b) = (tour[i], tour[i + 1]);
+                    let (c, e) = (tour[j], tour[(j + 1) % n]);
+                    if d[a][c] + d[b][e] < d[a][b] + d[c][e] {
+                        tour[i + 1..=j].reverse();
+                        improved = true;
+                    }
+                }
+            }
+        }
+        into_tour(&vertices, &d, &tour)
+    }
+
+    /// Improves `tour` with the Or-opt local search. While there is a segment with up to 3
+    /// consecutive vertices of the tour that can be moved, possibly reversed, to between two
+    /// others consecutive vertices decreasing the cost of the tour, the segment is moved.
+    ///
+    /// Each pass over the segments takes `O(n^2)` time.
+    ///
+    /// # Panics
+    ///
+    /// If the graph is not complete or if `tour` is not a tour.
+    fn or_opt<W, T>(&self, w: W, tour: Vec<Vertex<Self>>) -> (Vec<Vertex<Self>>, T)
+    where
+        Self: IncidenceGraph,
+        W: EdgePropGet<Self, T>,
+        T: Copy + Ord + Zero,
+    {
+        let (vertices, index, d) = distances(self, &w);
+        let mut tour = from_tour::<Self>(&index, vertices.len(), &tour);
+        let n = tour.len();
+        let mut improved = true;
+        while improved {
+            improved = false;
+            for len in (1..4).filter(|&len| len + 2 < n) {
+                for _ in 0..n {
+                    // the segment is tour[..len], it is removed from between prev and next
+                    let (first, last) = (tour[0], tour[len - 1]);
+                    let (prev, next) = (tour[n - 1], tour[len]);
+                    let removed = d[prev][first] + d[last][next];
+                    for j in len..n - 1 {
+                        let (x, y) = (tour[j], tour[j + 1]);
+                        let old = removed + d[x][y];
+                        let forward = d[prev][next] + d[x][first] + d[last][y];
+                        let backward = d[prev][next] + d[x][last] + d[first][y];
+                        if forward < old || backward < old {
+                            let mut segment = tour[..len].to_vec();
+                            if backward < forward {
+                                segment.reverse();
+                            }
+                            let mut new = tour[len..=j].to_vec();
+                            new.extend(segment);
+                            new.extend_from_slice(&tour[j + 1..]);
+                            tour = new;
+                            improved = true;
+                            break;
+                        }
+                    }
+                    tour.rotate_left(1);
+                }
+            }
+        }
+        into_tour(&vertices, &d, &tour)
+    }
+}
+
+impl<G: Incidence> Tsp for G {}
+
+// Returns the vertices, the index of each vertex and the matrix with the distances between each
+// pair of vertices.
+fn distances<G, W, T>(g: &G, w: &W) -> (Vec<Vertex<G>>, DefaultVertexPropMut<G, usize>, Vec<Vec<T>>)
+where
+    G: IncidenceGraph,
+    W: EdgePropGet<G, T>,
+    T: Copy + Ord + Zero,
+{
+    let vertices: Vec<_> = g.vertices().collect();
+    let index = vertex_index(g, &vertices);
+    let n = vertices.len();
+    let mut d = vec![vec![None; n]; n];
+    for (e, u, v) in g.edges_with_ends() {
+        let (u, v) = (index[u], index[v]);
+        let x = w.get(e);
+        match d[u][v] {
+            Some(y) if y <= x => (),
+            _ => {
+                d[u][v] = Some(x);
+                d[v][u] = Some(x);
+            }
+        }
+    }
+    let d = d
+        .into_iter()
+        .enumerate()
+        .map(|(u, row)| {
+            row.into_iter()
+                .enumerate()
+                .map(|(v, x)| match x {
+                    Some(x) => x,
+                    None if u == v => T::zero(),
+                    None => panic!("the graph is not complete"),
+                })
+                .collect()
+        })
+        .collect();
+    (vertices, index, d)
+}
+
+// Converts a tour of vertices to a tour of indices.
+fn from_tour<G>(index: &DefaultVertexPropMut<G, usize>, n: usize, tour: &[Vertex<G>]) -> Vec<usize>
+where
+    G: IncidenceGraph,
+{
+    let mut visited = vec![false; n];
+    let tour: Vec<_> = tour.iter().map(|&v| index[v]).collect();
+    for &v in &tour {
+        assert!(!visited[v], "the tour has a repeated vertex");
+        visited[v] = true;
+    }
+    assert_eq!(n, tour.len(), "the tour does not have all vertices");
+    tour
+}
+
+// Converts a tour of indices to a tour of vertices and computes its cost.
+fn into_tour<V: Copy, T>(vertices: &[V], d: &[Vec<T>], tour: &[usize]) -> (Vec<V>, T)
+where
+    T: Copy + Zero,
+{
+    let n = tour.len();
+    let cost = (0..n).fold(T::zero(), |acc, i| acc + d[tour[i]][tour[(i + 1) % n]]);
+    (tour.iter().map(|&i| vertices[i]).collect(), cost)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fera_fun::vec;
+
+    fn points(points: &[(i32, i32)]) -> (CompleteGraph, DefaultEdgePropMut<CompleteGraph, i32>) {
+        let g = CompleteGraph::new(points.len() as u32);
+        let w = g.edge_prop_from_fn(|e| {
+            let (x1, y1) = points[g.source(e) as usize];
+            let (x2, y2) = points[g.target(e) as usize];
+            (x1 - x2).abs() + (y1 - y2).abs()
+        });
+        (g, w)
+    }
+
+    #[test]
+    fn nearest_neighbor_tour() {
+        let (g, w) = points(&[(0, 0), (5, 0), (1, 0), (0, 1), (5, 1)]);
+        assert_eq!((vec![0, 2, 3, 4, 1], 14), g.nearest_neighbor_tour(&w));
+        let (g, w) = points(&[(0, 0)]);
+        assert_eq!((vec![0], 0), g.nearest_neighbor_tour(&w));
+    }
+
+    #[test]
+    fn christofides_tour() {
+        // a star, the tree is the star and the matching pairs the leaves
+        let (g, w) = points(&[(1, 1), (0, 1), (2, 1), (1, 0), (1, 2)]);
+        let (mut tour, cost) = g.christofides_tour(&w);
+        assert_eq!(8, cost);
+        tour.sort();
+        assert_eq!(vec(g.vertices()), tour);
+    }
+
+    #[test]
+    fn local_search() {
+        // the tour crosses itself
+        let (g, w) = points(&[(0, 0), (2, 2), (2, 0), (0, 2)]);
+        assert_eq!((vec![0, 2, 1, 3], 8), g.two_opt(&w, vec![0, 1, 2, 3]));
+        // the vertex 1 is in the wrong place
+        let (g, w) = points(&[(0, 0), (3, 3), (1, 0), (2, 0), (3, 0), (3, 1), (0, 1)]);
+        let (tour, cost) = g.or_opt(&w, vec![0, 1, 2, 3, 4, 5, 6]);
+        assert_eq!(12, cost);
+        assert_eq!(cost, g.or_opt(&w, tour).1);
+    }
+}
@@ -617,7 +617,7 @@ where
     G::Kind: UniformEdgeKind,
 {
     if G::Kind::is_directed() {
-        n * n
+        n * n - n
     } else {
         (n * n - n) / 2
     }
@@ -1031,4 +1031,17 @@ mod tests {
         let mean = sum.unwrap() / times;
         assert!(27 == mean || 28 == mean || 29 == mean);
     }
+
+    #[test]
+    fn gnm_digraph_max_num_edges() {
+        let mut rng = SmallRng::from_entropy();
+        for n in 1..6 {
+            let m = n * n - n;
+            let g = StaticDigraph::new_gnm(n, m, &mut rng).unwrap();
+            assert_eq!(m, g.num_edges());
+            assert!(g.edges_ends().all(|(u, v)| u != v));
+            assert!(StaticDigraph::new_gnm(n, m + 1, &mut rng).is_none());
+            assert!(StaticDigraph::new_gn(n, &mut rng).num_edges() < m.max(1));
+        }
+    }
 }
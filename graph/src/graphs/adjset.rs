@@ -334,6 +334,13 @@ where
     type VertexProp = HashMapProp<V, T>;
 }
 
+impl<V, K> BasicVertexProps for AdjSet<V, K>
+where
+    V: AdjSetVertex,
+    K: AdjSetEdgeKind<V>,
+{
+}
+
 impl<V, K, T> WithEdgeProp<T> for AdjSet<V, K>
 where
     V: AdjSetVertex,
@@ -343,6 +350,20 @@ where
     type EdgeProp = HashMapProp<K::Edge, T>;
 }
 
+impl<V, K> BasicEdgeProps for AdjSet<V, K>
+where
+    V: AdjSetVertex,
+    K: AdjSetEdgeKind<V>,
+{
+}
+
+impl<V, K> BasicProps for AdjSet<V, K>
+where
+    V: AdjSetVertex,
+    K: AdjSetEdgeKind<V>,
+{
+}
+
 // Iterators
 
 pub struct Edges<'a, V, K>
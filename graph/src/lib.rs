@@ -13,6 +13,9 @@ extern crate itertools;
 #[cfg(feature = "quickcheck")]
 extern crate quickcheck;
 
+#[cfg(feature = "rayon")]
+extern crate rayon;
+
 extern crate fera_ext;
 extern crate fera_fun;
 extern crate fera_optional;
@@ -133,6 +133,42 @@ macro_rules! generic_struct {
             }
         }
     );
+    ($(#[$attr:meta])* pub struct $S:ident($zero:ident, $one:ident, $two:ident,
+                                           $three:ident, $four:ident, $five:ident,
+                                           $six:ident)) => (
+        $(#[$attr])*
+        pub struct $S<A, B, C, D, E, F, G>(pub A, pub B, pub C, pub D, pub E, pub F, pub G);
+
+        impl<A, B, C, D, E, F, G> $S<A, B, C, D, E, F, G> {
+            pub fn $zero<N>(self, zero: N) -> $S<N, B, C, D, E, F, G> {
+                $S(zero, self.1, self.2, self.3, self.4, self.5, self.6)
+            }
+
+            pub fn $one<N>(self, one: N) -> $S<A, N, C, D, E, F, G> {
+                $S(self.0, one, self.2, self.3, self.4, self.5, self.6)
+            }
+
+            pub fn $two<N>(self, two: N) -> $S<A, B, N, D, E, F, G> {
+                $S(self.0, self.1, two, self.3, self.4, self.5, self.6)
+            }
+
+            pub fn $three<N>(self, three: N) -> $S<A, B, C, N, E, F, G> {
+                $S(self.0, self.1, self.2, three, self.4, self.5, self.6)
+            }
+
+            pub fn $four<N>(self, four: N) -> $S<A, B, C, D, N, F, G> {
+                $S(self.0, self.1, self.2, self.3, four, self.5, self.6)
+            }
+
+            pub fn $five<N>(self, five: N) -> $S<A, B, C, D, E, N, G> {
+                $S(self.0, self.1, self.2, self.3, self.4, five, self.6)
+            }
+
+            pub fn $six<N>(self, six: N) -> $S<A, B, C, D, E, F, N> {
+                $S(self.0, self.1, self.2, self.3, self.4, self.5, six)
+            }
+        }
+    );
 }
 
 pub trait ParamDerefMut {
@@ -0,0 +1,78 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+#[cfg(feature = "quickcheck")]
+#[macro_use]
+extern crate quickcheck;
+extern crate fera_graph;
+
+#[cfg(feature = "quickcheck")]
+mod quickchecks {
+    use fera_graph::algs::Arborescence;
+    use fera_graph::arbitrary::GnWithEdgeProp;
+    use fera_graph::prelude::*;
+    use fera_graph::sum_prop;
+
+    // Computes the weight of a minimum arborescence rooted at 0 by trying all the choices of one
+    // entering edge for each vertex.
+    fn min_arborescence_naive(
+        g: &StaticDigraph,
+        w: &DefaultEdgePropMut<StaticDigraph, i32>,
+    ) -> Option<i32> {
+        fn go(
+            g: &StaticDigraph,
+            w: &DefaultEdgePropMut<StaticDigraph, i32>,
+            v: usize,
+            chosen: &mut Vec<Edge<StaticDigraph>>,
+        ) -> Option<i32> {
+            if v == g.num_vertices() {
+                // every vertex reaches 0 following the chosen edges backwards
+                let reaches_root = (1..v).all(|mut u| {
+                    for _ in 0..v {
+                        if u != 0 {
+                            u = g.source(chosen[u - 1]) as usize;
+                        }
+                    }
+                    u == 0
+                });
+                return if reaches_root {
+                    Some(sum_prop(w, &*chosen))
+                } else {
+                    None
+                };
+            }
+            let mut best = None;
+            for e in g
+                .edges()
+                .filter(|&e| g.target(e) == v as u32 && g.source(e) != v as u32)
+            {
+                chosen.push(e);
+                if let Some(x) = go(g, w, v + 1, chosen) {
+                    best = Some(best.map_or(x, |b: i32| b.min(x)));
+                }
+                chosen.pop();
+            }
+            best
+        }
+        go(g, w, 1, &mut vec![])
+    }
+
+    quickcheck! {
+        fn min_arborescence(x: GnWithEdgeProp<StaticDigraph, i8>) -> bool {
+            let GnWithEdgeProp(g, w) = x;
+            if g.num_vertices() == 0 || g.num_vertices() > 7 {
+                return true;
+            }
+            let w = g.edge_prop_from_fn(|e| i32::from(w[e]));
+            let tree = g.min_arborescence(0, &w);
+            if let Some(ref tree) = tree {
+                assert_eq!(g.num_vertices() - 1, tree.len());
+                for (i, &e) in tree.iter().enumerate() {
+                    assert_eq!(i as u32 + 1, g.target(e));
+                }
+            }
+            min_arborescence_naive(&g, &w) == tree.map(|tree| sum_prop(&w, &tree))
+        }
+    }
+}
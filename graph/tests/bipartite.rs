@@ -0,0 +1,29 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+#[cfg(feature = "quickcheck")]
+#[macro_use]
+extern crate quickcheck;
+extern crate fera_graph;
+
+#[cfg(feature = "quickcheck")]
+mod quickchecks {
+    use fera_graph::algs::{Bipartite, Paths};
+    use fera_graph::arbitrary::Gn;
+    use fera_graph::prelude::*;
+
+    quickcheck! {
+        fn bipartition(g: Gn<StaticGraph>) -> bool {
+            let g = g.0;
+            match g.bipartition() {
+                Ok(side) => g.edges_ends().all(|(u, v)| side[u] != side[v]),
+                Err(cycle) => {
+                    cycle.len() % 2 == 1
+                        && g.is_walk(&cycle)
+                        && g.source(cycle[0]) == g.target(cycle[cycle.len() - 1])
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,73 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+#[cfg(feature = "quickcheck")]
+#[macro_use]
+extern crate quickcheck;
+extern crate fera_graph;
+
+#[cfg(feature = "quickcheck")]
+mod quickchecks {
+    use fera_graph::algs::Coloring;
+    use fera_graph::arbitrary::Gn;
+    use fera_graph::prelude::*;
+
+    // Computes the chromatic number by dynamic programming over the subsets of vertices, a set is
+    // colored by removing an independent set that contains its first vertex.
+    fn chromatic_number_naive(g: &StaticGraph) -> usize {
+        let n = g.num_vertices();
+        let mut adj = vec![0usize; n];
+        for (u, v) in g.edges_ends() {
+            let (u, v) = (u as usize, v as usize);
+            if u != v {
+                adj[u] |= 1 << v;
+                adj[v] |= 1 << u;
+            }
+        }
+        let independent = |set: usize| (0..n).all(|u| set & (1 << u) == 0 || set & adj[u] == 0);
+        let mut chi = vec![0; 1 << n];
+        for set in 1..1usize << n {
+            let first = set & set.wrapping_neg();
+            let rest = set & !first;
+            chi[set] = usize::max_value();
+            // iterates over the subsets of rest
+            let mut sub = rest;
+            loop {
+                if independent(sub | first) {
+                    chi[set] = chi[set].min(1 + chi[rest & !sub]);
+                }
+                if sub == 0 {
+                    break;
+                }
+                sub = (sub - 1) & rest;
+            }
+        }
+        chi[(1 << n) - 1]
+    }
+
+    quickcheck! {
+        fn coloring(g: Gn<StaticGraph>) -> bool {
+            let g = g.0;
+            if g.num_vertices() > 10 {
+                return true;
+            }
+            let (k, color) = g.minimum_coloring();
+            assert!(g.is_proper_coloring(&color));
+            assert!(g.vertices().all(|v| color[v] < k));
+            assert_eq!(chromatic_number_naive(&g), k);
+            let heuristics = vec![
+                g.greedy_coloring(g.vertices()),
+                g.greedy_coloring(g.largest_first_order()),
+                g.greedy_coloring(g.smallest_last_order()),
+                g.dsatur(),
+            ];
+            for (h, color) in heuristics {
+                assert!(g.is_proper_coloring(&color));
+                assert!(g.vertices().all(|v| color[v] < h));
+                assert!(k <= h);
+            }
+            true
+        }
+    }
+}
@@ -10,7 +10,7 @@ extern crate fera_graph;
 #[cfg(feature = "quickcheck")]
 mod quickchecks {
     use fera_graph::algs::components::{cut_edges_naive, cut_vertices_naive};
-    use fera_graph::algs::Components;
+    use fera_graph::algs::{Components, Cycles, Paths};
     use fera_graph::prelude::*;
 
     use fera_graph::arbitrary::Gn;
@@ -40,5 +40,60 @@ mod quickchecks {
             let actual = sorted(g.cut_edges());
             expect == actual
         }
+
+        fn biconnected_components(g: Gn<StaticGraph>) -> bool {
+            let g = g.0;
+            if g.num_vertices() > 20 {
+                return true
+            }
+            let bcc = g.biconnected_components();
+            for b in 0..bcc.num_components() {
+                let block = g.edge_induced_subgraph(g.edges().filter(|&e| bcc.component(e) == b));
+                assert!(block.num_edges() > 0);
+                assert!(block.is_connected());
+                assert!(block.num_edges() == 1 || block.cut_vertices().is_empty());
+            }
+            let (tree, cuts) = bcc.block_cut_tree(&g);
+            assert!(tree.is_acyclic());
+            assert_eq!(sorted(cut_vertices_naive(&g)), sorted(cuts));
+            true
+        }
+
+        fn two_edge_connected_components(g: Gn<StaticGraph>) -> bool {
+            let g = g.0;
+            if g.num_vertices() > 20 {
+                return true
+            }
+            let cuts = cut_edges_naive(&g);
+            let cc = g.spanning_subgraph(g.edges().filter(|e| !cuts.contains(e)))
+                .connected_components();
+            let comps = g.two_edge_connected_components();
+            assert_eq!(cc.num_components(), comps.num_components());
+            for u in g.vertices() {
+                for v in g.vertices() {
+                    assert_eq!(cc.is_connected(u, v), comps.is_connected(u, v));
+                }
+            }
+            true
+        }
+
+        fn strongly_connected_components(g: Gn<StaticDigraph>) -> bool {
+            let g = g.0;
+            if g.num_vertices() > 20 {
+                return true
+            }
+            let scc = g.strongly_connected_components();
+            let reach = |u, v| u == v || g.find_path(u, v).is_some();
+            for u in g.vertices() {
+                for v in g.vertices() {
+                    assert_eq!(reach(u, v) && reach(v, u), scc.is_connected(u, v));
+                }
+            }
+            let dag = scc.condensation(&g);
+            assert_eq!(scc.num_components(), dag.num_vertices());
+            assert!(dag.is_dag());
+            assert!(dag.edges_ends().all(|(a, b)| a > b));
+            true
+        }
     }
 }
@@ -0,0 +1,78 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+#[cfg(feature = "quickcheck")]
+#[macro_use]
+extern crate quickcheck;
+extern crate fera_graph;
+
+#[cfg(feature = "quickcheck")]
+mod quickchecks {
+    use fera_graph::algs::{Components, Connectivity};
+    use fera_graph::arbitrary::Gn;
+    use fera_graph::prelude::*;
+
+    // Returns true if the graph without the vertices removed is disconnected or trivial.
+    fn is_separator(g: &StaticGraph, removed: &[u32]) -> bool {
+        let kept: Vec<_> = g.vertices().filter(|v| !removed.contains(v)).collect();
+        kept.len() <= 1 || !g.induced_subgraph(kept).is_connected()
+    }
+
+    // Returns true if the graph without the edges removed is disconnected.
+    fn is_cut(g: &StaticGraph, removed: &[Edge<StaticGraph>]) -> bool {
+        !g.spanning_subgraph(g.edges().filter(|e| !removed.contains(e)))
+            .is_connected()
+    }
+
+    // Returns the size of the smallest subset of items for which f returns true.
+    fn min_subset<T: Copy, F>(items: &[T], f: F) -> usize
+    where
+        F: Fn(&[T]) -> bool,
+    {
+        (0..1u32 << items.len())
+            .filter_map(|mask| {
+                let subset: Vec<_> = (0..items.len())
+                    .filter(|&i| mask & (1 << i) != 0)
+                    .map(|i| items[i])
+                    .collect();
+                if f(&subset) {
+                    Some(subset.len())
+                } else {
+                    None
+                }
+            })
+            .min()
+            .unwrap()
+    }
+
+    quickcheck! {
+        fn vertex_connectivity(x: Gn<StaticGraph>) -> bool {
+            let Gn(g) = x;
+            let n = g.num_vertices();
+            if n == 0 || n > 10 {
+                return true;
+            }
+            let (k, separator) = g.vertex_connectivity();
+            assert_eq!(k, separator.len());
+            assert!(is_separator(&g, &separator));
+            assert!(g.is_k_connected(k) && !g.is_k_connected(k + 1));
+            let vertices: Vec<_> = g.vertices().collect();
+            k == min_subset(&vertices, |s| is_separator(&g, s))
+        }
+
+        fn edge_connectivity(x: Gn<StaticGraph>) -> bool {
+            let Gn(g) = x;
+            let n = g.num_vertices();
+            if n < 2 || g.num_edges() > 12 {
+                return true;
+            }
+            let (k, cut) = g.edge_connectivity();
+            assert_eq!(k, cut.len());
+            assert!(is_cut(&g, &cut));
+            assert!(g.is_k_edge_connected(k) && !g.is_k_edge_connected(k + 1));
+            let edges: Vec<_> = g.edges().collect();
+            k == min_subset(&edges, |s| is_cut(&g, s))
+        }
+    }
+}
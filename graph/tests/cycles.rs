@@ -0,0 +1,46 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+#[cfg(feature = "quickcheck")]
+#[macro_use]
+extern crate quickcheck;
+extern crate fera_graph;
+
+#[cfg(feature = "quickcheck")]
+mod quickchecks {
+    use fera_graph::algs::{Cycles, Paths};
+    use fera_graph::prelude::*;
+
+    use fera_graph::arbitrary::Gn;
+
+    fn check(g: &StaticDigraph, res: Result<Vec<Vertex<StaticDigraph>>, Vec<Edge<StaticDigraph>>>) {
+        match res {
+            Ok(order) => {
+                assert!(g.is_dag());
+                let mut pos = g.default_vertex_prop(usize::max_value());
+                for (i, &v) in order.iter().enumerate() {
+                    pos[v] = i;
+                }
+                assert_eq!(g.num_vertices(), order.len());
+                assert!(g.vertices().all(|v| pos[v] != usize::max_value()));
+                assert!(g.edges_ends().all(|(u, v)| pos[u] < pos[v]));
+            }
+            Err(cycle) => {
+                assert!(!g.is_dag());
+                assert!(!cycle.is_empty());
+                assert!(g.is_walk(&cycle));
+                assert_eq!(g.source(cycle[0]), g.target(*cycle.last().unwrap()));
+            }
+        }
+    }
+
+    quickcheck! {
+        fn topological_sort(g: Gn<StaticDigraph>) -> bool {
+            let g = g.0;
+            check(&g, g.topological_sort());
+            check(&g, g.lexicographic_topological_sort());
+            true
+        }
+    }
+}
@@ -0,0 +1,126 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+#[cfg(feature = "quickcheck")]
+#[macro_use]
+extern crate quickcheck;
+extern crate fera_graph;
+
+#[cfg(feature = "quickcheck")]
+mod quickchecks {
+    use fera_graph::algs::{Eulerian, Paths};
+    use fera_graph::arbitrary::Gn;
+    use fera_graph::prelude::*;
+
+    // Returns the number of unbalanced vertices (with odd degree for graphs) and if all edges are
+    // in the same (weakly) connected component.
+    fn naive<G>(g: &G) -> (usize, bool)
+    where
+        G: VertexList + EdgeList + BasicProps,
+        G::Kind: UniformEdgeKind,
+    {
+        let mut balance = g.default_vertex_prop(0i32);
+        let mut index = g.default_vertex_prop(0usize);
+        for (i, v) in g.vertices().enumerate() {
+            index[v] = i;
+        }
+        let mut comp: Vec<usize> = (0..g.num_vertices()).collect();
+        for (u, v) in g.edges_ends() {
+            balance[u] += 1;
+            balance[v] += if G::Kind::is_directed() { -1 } else { 1 };
+            let (a, b) = (comp[index[u]], comp[index[v]]);
+            for c in &mut comp {
+                if *c == b {
+                    *c = a;
+                }
+            }
+        }
+        let unbalanced = g
+            .vertices()
+            .filter(|&v| balance[v] % 2 != 0 || G::Kind::is_directed() && balance[v] != 0)
+            .count();
+        let mut comps: Vec<_> = g.edges_ends().map(|(u, _)| comp[index[u]]).collect();
+        comps.dedup();
+        (
+            unbalanced,
+            comps.len() <= 1 || comps.iter().all(|&c| c == comps[0]),
+        )
+    }
+
+    fn check<G>(g: &G) -> bool
+    where
+        G: Incidence + VertexList + EdgeList + BasicProps,
+        G::Kind: UniformEdgeKind,
+    {
+        if g.num_vertices() > 30 {
+            return true;
+        }
+        let (unbalanced, connected) = naive(g);
+        let mut balanced_ends = true;
+        if G::Kind::is_directed() && unbalanced == 2 {
+            let mut balance = g.default_vertex_prop(0i32);
+            for (u, v) in g.edges_ends() {
+                balance[u] += 1;
+                balance[v] -= 1;
+            }
+            balanced_ends = g.vertices().all(|v| balance[v].abs() <= 1);
+        }
+        for &(ref walk, closed) in &[(g.eulerian_circuit(), true), (g.eulerian_path(), false)] {
+            if let Some(ref walk) = *walk {
+                let mut count = g.default_edge_prop(0usize);
+                for &e in walk {
+                    count[e] += 1;
+                }
+                assert!(g.edges().all(|e| count[e] == 1));
+                assert_eq!(g.num_edges(), walk.len());
+                assert!(g.is_walk(walk));
+                if closed && !walk.is_empty() {
+                    assert_eq!(g.source(walk[0]), g.target(walk[walk.len() - 1]));
+                }
+            } else {
+                let possible = if closed {
+                    unbalanced == 0
+                } else {
+                    unbalanced <= 2 && balanced_ends
+                };
+                assert!(!connected || !possible);
+            }
+        }
+        assert_eq!(g.is_eulerian(), connected && unbalanced == 0);
+        true
+    }
+
+    quickcheck! {
+        fn eulerian_graph(g: Gn<StaticGraph>) -> bool {
+            check(&g.0)
+        }
+
+        fn eulerian_digraph(g: Gn<StaticDigraph>) -> bool {
+            check(&g.0)
+        }
+
+        fn eulerian_graph_even_degree(g: Gn<StaticGraph>) -> bool {
+            // pairs the vertices with odd degree, so all vertices have even degree
+            let g = g.0;
+            let mut edges: Vec<_> = g.edges_ends().map(|(u, v)| (u as usize, v as usize)).collect();
+            let odd: Vec<_> = g.vertices().filter(|&v| g.out_degree(v) % 2 == 1).collect();
+            for p in odd.chunks(2) {
+                edges.push((p[0] as usize, p[1] as usize));
+            }
+            let g = StaticGraph::new_with_edges(g.num_vertices(), edges);
+            assert!(g.num_vertices() > 30 || naive(&g).1 == g.is_eulerian());
+            check(&g)
+        }
+
+        fn eulerian_digraph_balanced(g: Gn<StaticDigraph>) -> bool {
+            // adds the reverse of each edge, so all vertices are balanced
+            let g = g.0;
+            let edges = g.edges_ends()
+                .flat_map(|(u, v)| vec![(u as usize, v as usize), (v as usize, u as usize)]);
+            let g = StaticDigraph::new_with_edges(g.num_vertices(), edges);
+            assert!(g.num_vertices() > 30 || naive(&g).1 == g.is_eulerian());
+            check(&g)
+        }
+    }
+}
@@ -0,0 +1,118 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+#[cfg(feature = "quickcheck")]
+#[macro_use]
+extern crate quickcheck;
+extern crate fera_graph;
+
+#[cfg(feature = "quickcheck")]
+mod quickchecks {
+    use fera_graph::algs::flow::Flow;
+    use fera_graph::algs::{MaxFlow, MinCostFlow};
+    use fera_graph::arbitrary::GnWithEdgeProp;
+    use fera_graph::prelude::*;
+
+    fn check<C>(
+        g: &StaticDigraph,
+        cap: &C,
+        (s, t): (Vertex<StaticDigraph>, Vertex<StaticDigraph>),
+        flow: &Flow<StaticDigraph, u32>,
+    ) where
+        C: EdgePropGet<StaticDigraph, u32>,
+    {
+        let mut side = g.default_vertex_prop(false);
+        for &v in &flow.source_side {
+            side[v] = true;
+        }
+        assert!(side[s] && !side[t]);
+        let mut balance = g.default_vertex_prop(0i64);
+        let mut cut = 0;
+        for (e, u, v) in g.edges_with_ends() {
+            assert!(flow.flow[e] <= cap.get(e));
+            balance[u] -= i64::from(flow.flow[e]);
+            balance[v] += i64::from(flow.flow[e]);
+            if side[u] && !side[v] {
+                // the edges that leave the cut are saturated
+                assert_eq!(cap.get(e), flow.flow[e]);
+                cut += cap.get(e);
+            }
+            if !side[u] && side[v] {
+                assert_eq!(0, flow.flow[e]);
+            }
+        }
+        assert_eq!(flow.value, cut);
+        assert_eq!(-i64::from(flow.value), balance[s]);
+        for v in g.vertices().filter(|&v| v != s && v != t) {
+            assert_eq!(0, balance[v]);
+        }
+    }
+
+    quickcheck! {
+        fn max_flow(x: GnWithEdgeProp<StaticDigraph, u8>) -> bool {
+            let GnWithEdgeProp(g, cap) = x;
+            if g.num_vertices() < 2 {
+                return true;
+            }
+            let cap = cap.map(u32::from);
+            let v: Vec<_> = g.vertices().collect();
+            let (s, t) = (v[0], v[v.len() - 1]);
+            let dinic = g.dinic(s, t, &cap);
+            let push_relabel = g.push_relabel(s, t, &cap);
+            assert_eq!(dinic.value, push_relabel.value);
+            check(&g, &cap, (s, t), &dinic);
+            check(&g, &cap, (s, t), &push_relabel);
+            true
+        }
+
+        fn min_cost_max_flow(x: GnWithEdgeProp<StaticDigraph, u8>) -> bool {
+            let GnWithEdgeProp(g, cap) = x;
+            if g.num_vertices() < 2 || g.num_vertices() > 20 {
+                return true;
+            }
+            let cap = cap.map(|c| i64::from(c % 16));
+            let mut cost = g.default_edge_prop(0i64);
+            for (i, e) in g.edges().enumerate() {
+                cost[e] = (i % 5) as i64;
+            }
+            let v: Vec<_> = g.vertices().collect();
+            let (s, t) = (v[0], v[v.len() - 1]);
+            let flow = g.min_cost_max_flow(s, t, &cap, &cost);
+            let dinic = g.dinic(s, t, (&cap).map(|c| c as u32));
+            assert_eq!(i64::from(dinic.value), flow.value);
+            let mut balance = g.default_vertex_prop(0i64);
+            let mut total = 0;
+            for (e, u, v) in g.edges_with_ends() {
+                assert!(0 <= flow.flow[e] && flow.flow[e] <= cap.get(e));
+                balance[u] -= flow.flow[e];
+                balance[v] += flow.flow[e];
+                total += flow.flow[e] * cost[e];
+            }
+            assert_eq!(total, flow.cost);
+            assert_eq!(-flow.value, balance[s]);
+            for v in g.vertices().filter(|&v| v != s && v != t) {
+                assert_eq!(0, balance[v]);
+            }
+            // the flow has minimum cost iff there is no negative cycle in the residual graph
+            let mut arcs = vec![];
+            for (e, u, v) in g.edges_with_ends() {
+                if flow.flow[e] < cap.get(e) {
+                    arcs.push((u, v, cost[e]));
+                }
+                if flow.flow[e] > 0 {
+                    arcs.push((v, u, -cost[e]));
+                }
+            }
+            let mut dist = g.default_vertex_prop(0i64);
+            for _ in 0..g.num_vertices() {
+                for &(u, v, c) in &arcs {
+                    if dist[u] + c < dist[v] {
+                        dist[v] = dist[u] + c;
+                    }
+                }
+            }
+            arcs.iter().all(|&(u, v, c)| dist[u] + c >= dist[v])
+        }
+    }
+}
@@ -0,0 +1,65 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+#[cfg(feature = "quickcheck")]
+#[macro_use]
+extern crate quickcheck;
+extern crate fera_graph;
+
+#[cfg(feature = "quickcheck")]
+mod quickchecks {
+    use fera_graph::algs::{Components, GomoryHu, Trees};
+    use fera_graph::arbitrary::GnWithEdgeProp;
+    use fera_graph::prelude::*;
+
+    type Weight = DefaultEdgePropMut<StaticGraph, u32>;
+
+    // Returns the weight of the cut with the vertices in mask in one side.
+    fn cut_weight(g: &StaticGraph, w: &Weight, mask: u32) -> u32 {
+        g.edges_with_ends()
+            .filter(|&(_, u, v)| (mask >> u) & 1 != (mask >> v) & 1)
+            .map(|(e, _, _)| w[e])
+            .sum()
+    }
+
+    quickcheck! {
+        fn gomory_hu_tree(x: GnWithEdgeProp<StaticGraph, u8>) -> bool {
+            let GnWithEdgeProp(g, w) = x;
+            let n = g.num_vertices();
+            if n > 10 {
+                return true;
+            }
+            let w: Weight = g.edge_prop_from_fn(|e| u32::from(w[e]));
+            let gh = g.gomory_hu_tree(&w);
+            assert!(n == 0 || gh.tree.is_tree());
+            assert_eq!(g.vertices().collect::<Vec<_>>(), gh.vertices);
+
+            // each edge of the tree induces a minimum cut between its ends
+            for (e, a, b) in gh.tree.edges_with_ends() {
+                let comp = gh.tree
+                    .spanning_subgraph(gh.tree.edges().filter(|&f| f != e))
+                    .connected_components();
+                let mask = (0..n as u32)
+                    .filter(|&v| comp.is_connected(a, v))
+                    .fold(0, |mask, v| mask | 1 << v);
+                assert!(comp.is_disconnected(a, b));
+                assert_eq!(gh.weight[e], cut_weight(&g, &w, mask));
+            }
+
+            // the minimum cut between each pair of vertices
+            let cuts: Vec<_> = (0..1u32 << n).map(|mask| cut_weight(&g, &w, mask)).collect();
+            for u in g.vertices() {
+                for v in g.vertices().filter(|&v| v != u) {
+                    let naive = (0..1u32 << n)
+                        .filter(|&mask| (mask >> u) & 1 == 1 && (mask >> v) & 1 == 0)
+                        .map(|mask| cuts[mask as usize])
+                        .min()
+                        .unwrap();
+                    assert_eq!(naive, gh.min_cut(u, v));
+                }
+            }
+            true
+        }
+    }
+}
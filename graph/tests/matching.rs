@@ -0,0 +1,212 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+#[cfg(feature = "quickcheck")]
+#[macro_use]
+extern crate quickcheck;
+extern crate fera_graph;
+
+#[cfg(feature = "quickcheck")]
+mod quickchecks {
+    use fera_graph::algs::Matching;
+    use fera_graph::arbitrary::{Gn, GnWithEdgeProp};
+    use fera_graph::prelude::*;
+    use fera_graph::props::FnProp;
+
+    fn check<G: IncidenceGraph>(
+        g: &G,
+        matching: &[Edge<G>],
+        mate: &DefaultVertexPropMut<G, OptionVertex<G>>,
+    ) {
+        let mut covered = g.default_vertex_prop(false);
+        for &e in matching {
+            let (u, v) = g.ends(e);
+            assert!(u != v && !covered[u] && !covered[v]);
+            covered[u] = true;
+            covered[v] = true;
+            assert_eq!(Some(v), mate[u].into_option());
+            assert_eq!(Some(u), mate[v].into_option());
+        }
+        for v in g.vertices() {
+            assert_eq!(covered[v], mate[v].into_option().is_some());
+        }
+    }
+
+    // Computes the size of a maximum matching by dynamic programming over the subsets of vertices.
+    fn max_matching_naive(g: &StaticGraph) -> usize {
+        let n = g.num_vertices();
+        let mut adj = vec![0usize; n];
+        for (u, v) in g.edges_ends() {
+            let (u, v) = (u as usize, v as usize);
+            if u != v {
+                adj[u] |= 1 << v;
+                adj[v] |= 1 << u;
+            }
+        }
+        let mut size = vec![0; 1 << n];
+        for mask in 1..1usize << n {
+            let u = mask.trailing_zeros() as usize;
+            let rest = mask & !(1 << u);
+            size[mask] = size[rest];
+            for v in 0..n {
+                if rest & adj[u] & (1 << v) != 0 {
+                    size[mask] = size[mask].max(1 + size[rest & !(1 << v)]);
+                }
+            }
+        }
+        size[(1 << n) - 1]
+    }
+
+    // Computes the cost of a minimum cost perfect matching between the even and odd vertices by
+    // trying all permutations.
+    fn min_cost_perfect_matching_naive(
+        g: &StaticGraph,
+        w: &DefaultEdgePropMut<StaticGraph, i64>,
+    ) -> Option<i64> {
+        fn go(cost: &[Vec<Option<i64>>], i: usize, used: &mut Vec<bool>) -> Option<i64> {
+            if i == cost.len() {
+                return Some(0);
+            }
+            let mut best = None;
+            for j in 0..cost.len() {
+                if used[j] {
+                    continue;
+                }
+                if let Some(c) = cost[i][j] {
+                    used[j] = true;
+                    if let Some(rest) = go(cost, i + 1, used) {
+                        best = Some(best.map_or(c + rest, |b: i64| b.min(c + rest)));
+                    }
+                    used[j] = false;
+                }
+            }
+            best
+        }
+        let n = g.num_vertices();
+        if n % 2 == 1 {
+            return None;
+        }
+        let mut cost = vec![vec![None; n / 2]; n / 2];
+        for (e, u, v) in g.edges_with_ends() {
+            let (u, v) = (u as usize, v as usize);
+            if u % 2 == v % 2 {
+                continue;
+            }
+            let (i, j) = if u % 2 == 0 {
+                (u / 2, v / 2)
+            } else {
+                (v / 2, u / 2)
+            };
+            cost[i][j] = Some(cost[i][j].map_or(w[e], |c: i64| c.min(w[e])));
+        }
+        go(&cost, 0, &mut vec![false; n / 2])
+    }
+
+    // Computes the weight of a maximum weight matching (or the weight of a minimum weight perfect
+    // matching if perfect is true) by dynamic programming over the subsets of vertices.
+    fn weighted_matching_naive(
+        g: &StaticGraph,
+        w: &DefaultEdgePropMut<StaticGraph, i64>,
+        perfect: bool,
+    ) -> Option<i64> {
+        let n = g.num_vertices();
+        let sign = if perfect { -1 } else { 1 };
+        let mut best = vec![None; 1 << n];
+        best[0] = Some(0);
+        for mask in 1..1usize << n {
+            let u = mask.trailing_zeros() as usize;
+            let rest = mask & !(1 << u);
+            let mut b = if perfect { None } else { best[rest] };
+            for (e, x, y) in g.edges_with_ends() {
+                let (x, y) = (x as usize, y as usize);
+                let v = if x == u {
+                    y
+                } else if y == u {
+                    x
+                } else {
+                    continue;
+                };
+                if v == u || rest & (1 << v) == 0 {
+                    continue;
+                }
+                if let Some(r) = best[rest & !(1 << v)] {
+                    let c = r + sign * w[e];
+                    b = Some(b.map_or(c, |b: i64| b.max(c)));
+                }
+            }
+            best[mask] = b;
+        }
+        best[(1 << n) - 1].map(|b| sign * b)
+    }
+
+    quickcheck! {
+        fn weighted_matching(x: GnWithEdgeProp<StaticGraph, i8>) -> bool {
+            let GnWithEdgeProp(g, w) = x;
+            if g.num_vertices() > 12 {
+                return true;
+            }
+            let w: DefaultEdgePropMut<StaticGraph, i64> = g.edge_prop_from_fn(|e| i64::from(w[e]));
+            let (matching, mate) = g.max_weight_matching(&w);
+            check(&g, &matching, &mate);
+            let weight = matching.iter().map(|&e| w[e]).sum::<i64>();
+            assert_eq!(weighted_matching_naive(&g, &w, false), Some(weight));
+            let perfect = g.min_weight_perfect_matching(&w);
+            if let Some((ref matching, ref mate)) = perfect {
+                check(&g, matching, mate);
+                assert_eq!(g.num_vertices(), 2 * matching.len());
+            }
+            let weight = perfect.map(|(matching, _)| matching.iter().map(|&e| w[e]).sum::<i64>());
+            weighted_matching_naive(&g, &w, true) == weight
+        }
+
+        fn hungarian(x: GnWithEdgeProp<StaticGraph, i8>) -> bool {
+            let GnWithEdgeProp(g, w) = x;
+            if g.num_vertices() > 14 {
+                return true;
+            }
+            let w = g.edge_prop_from_fn(|e| i64::from(w[e]));
+            let a = g.hungarian(FnProp(|v| v % 2 == 1), &w);
+            assert_eq!(min_cost_perfect_matching_naive(&g, &w), a.as_ref().map(|a| a.cost));
+            if let Some(a) = a {
+                check(&g, &a.edges, &a.mate);
+                assert_eq!(a.cost, a.edges.iter().map(|&e| w[e]).sum::<i64>());
+                assert_eq!(a.cost, g.vertices().map(|v| a.potential[v]).sum::<i64>());
+                for (e, u, v) in g.edges_with_ends() {
+                    assert!(u % 2 == v % 2 || a.potential[u] + a.potential[v] <= w[e]);
+                }
+            }
+            true
+        }
+
+        fn edmonds(g: Gn<StaticGraph>) -> bool {
+            let g = g.0;
+            if g.num_vertices() > 12 {
+                return true;
+            }
+            let (matching, mate) = g.edmonds();
+            check(&g, &matching, &mate);
+            max_matching_naive(&g) == matching.len()
+        }
+
+        fn hopcroft_karp(g: Gn<StaticGraph>) -> bool {
+            let g = g.0;
+            if g.num_vertices() > 12 {
+                return true;
+            }
+            let side = FnProp(|v| v % 2 == 0);
+            let (matching, mate) = g.hopcroft_karp(side);
+            check(&g, &matching, &mate);
+            let crossing = g.spanning_subgraph(g.edges().filter(|&e| {
+                let (u, v) = g.ends(e);
+                u % 2 != v % 2
+            }));
+            assert_eq!(crossing.edmonds().0.len(), matching.len());
+            if let Some((matching, mate)) = g.bipartite_matching() {
+                check(&g, &matching, &mate);
+                assert_eq!(max_matching_naive(&g), matching.len());
+            }
+            true
+        }
+    }
+}
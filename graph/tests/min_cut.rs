@@ -0,0 +1,84 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+#[cfg(feature = "quickcheck")]
+#[macro_use]
+extern crate quickcheck;
+extern crate fera_graph;
+extern crate rand;
+
+#[cfg(feature = "quickcheck")]
+mod quickchecks {
+    use fera_graph::algs::MinCut;
+    use fera_graph::arbitrary::GnWithEdgeProp;
+    use fera_graph::prelude::*;
+    use rand::rngs::SmallRng;
+    use rand::SeedableRng;
+
+    type Weight = DefaultEdgePropMut<StaticGraph, u32>;
+
+    fn cut_weight(g: &StaticGraph, w: &Weight, side: &[u32]) -> u32 {
+        g.edges_with_ends()
+            .filter(|&(_, u, v)| side.contains(&u) != side.contains(&v))
+            .map(|(e, _, _)| w[e])
+            .sum()
+    }
+
+    // Checks that side is a proper subset of the vertices and returns the weight of the cut.
+    fn check(g: &StaticGraph, w: &Weight, side: &[u32]) -> u32 {
+        let mut sorted = side.to_vec();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(side.len(), sorted.len());
+        assert!(!side.is_empty() && side.len() < g.num_vertices());
+        cut_weight(g, w, side)
+    }
+
+    // Computes the weight of a minimum cut by trying all the sides with the vertex 0.
+    fn min_cut_naive(g: &StaticGraph, w: &Weight) -> u32 {
+        let n = g.num_vertices();
+        (0..1u32 << (n - 1))
+            .map(|mask| {
+                (0..n as u32)
+                    .filter(|&v| v == 0 || mask & (1 << (v - 1)) != 0)
+                    .collect::<Vec<_>>()
+            })
+            .filter(|side| side.len() < n)
+            .map(|side| cut_weight(g, w, &side))
+            .min()
+            .unwrap()
+    }
+
+    quickcheck! {
+        fn stoer_wagner(x: GnWithEdgeProp<StaticGraph, u8>) -> bool {
+            let GnWithEdgeProp(g, w) = x;
+            if g.num_vertices() > 12 {
+                return true;
+            }
+            let w: Weight = g.edge_prop_from_fn(|e| u32::from(w[e]));
+            match g.stoer_wagner(&w) {
+                Some((weight, side)) => {
+                    assert_eq!(weight, check(&g, &w, &side));
+                    min_cut_naive(&g, &w) == weight
+                }
+                None => g.num_vertices() < 2,
+            }
+        }
+
+        fn karger_stein(x: GnWithEdgeProp<StaticGraph, u8>, seed: u64) -> bool {
+            let GnWithEdgeProp(g, w) = x;
+            if g.num_vertices() > 12 {
+                return true;
+            }
+            let w: Weight = g.edge_prop_from_fn(|e| u32::from(w[e]));
+            match g.karger_stein(&w, SmallRng::seed_from_u64(seed)) {
+                Some((weight, side)) => {
+                    assert_eq!(weight, check(&g, &w, &side));
+                    min_cut_naive(&g, &w) == weight
+                }
+                None => g.num_vertices() < 2,
+            }
+        }
+    }
+}
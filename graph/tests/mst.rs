@@ -11,8 +11,8 @@ extern crate fera_graph;
 #[cfg(feature = "quickcheck")]
 mod quickchecks {
     use fera_fun::vec;
-    use fera_graph::algs::{Boruvka, Kruskal, Prim, Trees};
-    use fera_graph::arbitrary::GnConnectedWithEdgeProp;
+    use fera_graph::algs::{Boruvka, Components, Kruskal, Prim, Trees};
+    use fera_graph::arbitrary::{GnConnectedWithEdgeProp, GnWithEdgeProp};
     use fera_graph::prelude::*;
     use fera_graph::sum_prop;
 
@@ -35,5 +35,62 @@ mod quickchecks {
             assert_eq!(w_boruvka, w_prim);
             true
         }
+
+        fn msf(x: GnWithEdgeProp<StaticGraph, u32>) -> bool {
+            let GnWithEdgeProp(g, w) = x;
+            if g.num_vertices() == 0 {
+                return true;
+            }
+            // rejects some edges, so the forest is a minimum spanning forest of the graph without
+            // the rejected edges
+            let index = g.edge_index();
+            let accept = |_: &StaticGraph, e| index.get(e) % 3 != 0;
+            let mut edges = vec(g.edges().filter(|&e| accept(&g, e)));
+            edges.sort_by_key(|&e| w[e]);
+            let expected = vec(g.kruskal().edges(&edges));
+            let components = g.spanning_subgraph(&edges).num_components() as usize;
+            let boruvka = g.boruvka(&w).visitor(accept).run();
+            let kruskal = vec(g.kruskal_mst(&w).mst_visitor(accept));
+            let prim = vec(g.prim(&w).visitor(accept));
+            for forest in &[boruvka, kruskal, prim] {
+                assert_eq!(g.num_vertices() - components, forest.len());
+                assert_eq!(components as u64, g.spanning_subgraph(forest).num_components());
+                assert!(forest.iter().all(|&e| accept(&g, e)));
+                let weight: u32 = sum_prop(&w, forest);
+                assert_eq!(sum_prop::<_, _, u32, _>(&w, &expected), weight);
+            }
+            true
+        }
+    }
+}
+
+#[cfg(all(feature = "quickcheck", feature = "rayon"))]
+mod par_quickchecks {
+    use fera_graph::algs::Boruvka;
+    use fera_graph::arbitrary::GnWithEdgeProp;
+    use fera_graph::prelude::*;
+
+    quickcheck! {
+        fn par_boruvka(x: GnWithEdgeProp<StaticGraph, u8>) -> bool {
+            // small weights to have many ties
+            let GnWithEdgeProp(g, mut w) = x;
+            if g.num_vertices() == 0 {
+                return true;
+            }
+            for e in g.edges() {
+                w[e] %= 4;
+            }
+            let seq = g.boruvka(&w).run();
+            let par = g.boruvka(&w).par_run();
+            let ends = |edges: &[Edge<StaticGraph>]| {
+                edges.iter().map(|&e| (g.source(e), g.target(e))).collect::<Vec<_>>()
+            };
+            assert_eq!(ends(&seq), ends(&par));
+            let index = g.edge_index();
+            let accept = |_: &StaticGraph, e| index.get(e) % 3 != 0;
+            let seq = g.boruvka(&w).visitor(accept).run();
+            let par = g.boruvka(&w).visitor(accept).par_run();
+            ends(&seq) == ends(&par)
+        }
     }
 }
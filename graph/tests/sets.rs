@@ -9,10 +9,82 @@ extern crate fera_graph;
 
 #[cfg(feature = "quickcheck")]
 mod quickchecks {
+    use fera_graph::algs::Sets;
+    use fera_graph::arbitrary::Gn;
     use fera_graph::prelude::*;
     use fera_graph::sets::FastVecSet;
 
+    // Returns the maximal cliques as bit sets by checking all subsets of vertices.
+    fn maximal_cliques_naive<G>(g: &G) -> Vec<usize>
+    where
+        G: AdjacencyGraph,
+    {
+        let v: Vec<_> = g.vertices().collect();
+        let n = v.len();
+        let mut adj = vec![0usize; n];
+        for i in 0..n {
+            for j in 0..n {
+                if i != j && g.get_edge_by_ends(v[i], v[j]).is_some() {
+                    adj[i] |= 1 << j;
+                }
+            }
+        }
+        let is_clique =
+            |set: usize| (0..n).all(|i| set & (1 << i) == 0 || set & !(1 << i) & !adj[i] == 0);
+        (1..1usize << n)
+            .filter(|&set| {
+                is_clique(set) && (0..n).all(|i| set & (1 << i) != 0 || !is_clique(set | (1 << i)))
+            })
+            .collect()
+    }
+
+    fn check_cliques<G>(g: &G) -> bool
+    where
+        G: AdjacencyGraph,
+    {
+        if g.num_vertices() > 12 {
+            return true;
+        }
+        let v: Vec<_> = g.vertices().collect();
+        let to_set = |clique: &[Vertex<G>]| {
+            clique.iter().fold(0, |set, &u| {
+                set | (1 << v.iter().position(|&x| x == u).unwrap())
+            })
+        };
+        let mut expected = maximal_cliques_naive(g);
+        let mut actual: Vec<_> = g.maximal_cliques().map(|c| to_set(&c)).collect();
+        expected.sort();
+        actual.sort();
+        assert_eq!(expected, actual);
+        let max = g.maximum_clique();
+        assert!(g.is_clique(&max));
+        let size = expected
+            .iter()
+            .map(|set| set.count_ones())
+            .max()
+            .unwrap_or(0);
+        assert_eq!(size as usize, max.len());
+        true
+    }
+
     quickcheck! {
+        fn cliques_static(g: Gn<StaticGraph>) -> bool {
+            check_cliques(&g.0)
+        }
+
+        fn cliques_adjset(g: Gn<StaticGraph>) -> bool {
+            let mut h = AdjSetGraph::new();
+            for v in g.0.vertices() {
+                h.add_vertex(v);
+            }
+            for (u, v) in g.0.edges_ends() {
+                if h.get_edge_by_ends(u, v).is_none() {
+                    h.add_edge(u, v);
+                }
+            }
+            check_cliques(&h)
+        }
+
         fn sets(vertices: Vec<u8>) -> bool {
             let n = 20;
             let g = CompleteGraph::new(n);
@@ -0,0 +1,93 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+#[cfg(feature = "quickcheck")]
+#[macro_use]
+extern crate quickcheck;
+extern crate fera_graph;
+
+#[cfg(feature = "quickcheck")]
+mod quickchecks {
+    use fera_graph::algs::shortest_paths::NegativeCycle;
+    use fera_graph::algs::{Distances, Paths, ShortestPaths};
+    use fera_graph::arbitrary::{Gn, GnWithEdgeProp};
+    use fera_graph::prelude::*;
+    use fera_graph::props::FnProp;
+    use fera_graph::sum_prop;
+
+    quickcheck! {
+        fn dijkstra_bellman_ford(x: GnWithEdgeProp<StaticGraph, u16>) -> bool {
+            let GnWithEdgeProp(g, w) = x;
+            let w = w.map(u32::from);
+            for s in g.vertices().take(3) {
+                let (dist1, _) = g.dijkstra(s, &w).run();
+                let (dist2, _) = g.bellman_ford(s, &w).unwrap();
+                assert!(g.vertices().all(|v| dist1[v] == dist2[v]));
+            }
+            true
+        }
+
+        fn astar_dijkstra(x: GnWithEdgeProp<StaticGraph, u16>) -> bool {
+            let GnWithEdgeProp(g, w) = x;
+            let w = w.map(u32::from);
+            for t in g.vertices().take(3) {
+                // the distances to t are the best possible heuristic
+                let (h, _) = g.dijkstra(t, &w).run();
+                let zero = FnProp(|_| 0);
+                for s in g.vertices() {
+                    let p1 = g.astar(s, t, &w, &zero);
+                    let p2 = g.astar(s, t, &w, &*h);
+                    if h[s] == u32::max_value() {
+                        assert!(p1.is_none() && p2.is_none());
+                        continue;
+                    }
+                    for path in vec![p1.unwrap(), p2.unwrap()] {
+                        assert!(g.is_path(&path));
+                        assert_eq!(h[s], sum_prop::<_, _, u32, _>(&w, &path));
+                    }
+                }
+            }
+            true
+        }
+
+        fn eccentricity(x: Gn<StaticGraph>) -> bool {
+            let Gn(g) = x;
+            if g.num_vertices() > 30 {
+                return true;
+            }
+            let w = g.default_edge_prop(1usize);
+            let ecc = g.eccentricities();
+            let weighted_ecc = g.weighted_eccentricities(&w);
+            assert!(g.vertices().all(|v| ecc[v] == weighted_ecc[v]));
+            assert_eq!(g.radius(), g.weighted_radius(&w));
+            assert_eq!(g.diameter(), g.weighted_diameter(&w).into_option().unwrap_or(usize::max_value()));
+            assert_eq!(g.center(), g.weighted_center(&w));
+            assert_eq!(g.periphery(), g.weighted_periphery(&w));
+            true
+        }
+
+        fn all_pairs(x: GnWithEdgeProp<StaticDigraph, i8>) -> bool {
+            let GnWithEdgeProp(g, w) = x;
+            if g.num_vertices() > 30 {
+                return true;
+            }
+            let w = w.map(|x| i32::from(x) + 20);
+            match (g.floyd_warshall(&w), g.johnson(&w)) {
+                (Ok(floyd_warshall), Ok(johnson)) => for u in g.vertices() {
+                    let (dist, _) = g.bellman_ford(u, &w).unwrap();
+                    for v in g.vertices() {
+                        assert_eq!(dist[v], floyd_warshall[(u, v)]);
+                        assert_eq!(dist[v], johnson[(u, v)]);
+                    }
+                },
+                (Err(NegativeCycle(c1)), Err(NegativeCycle(c2))) => {
+                    assert!(g.is_walk(&c1) && sum_prop::<_, _, i32, _>(&w, &c1) < 0);
+                    assert!(g.is_walk(&c2) && sum_prop::<_, _, i32, _>(&w, &c2) < 0);
+                }
+                _ => panic!(),
+            }
+            true
+        }
+    }
+}
@@ -0,0 +1,105 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+#[cfg(feature = "quickcheck")]
+#[macro_use]
+extern crate quickcheck;
+extern crate fera_fun;
+extern crate fera_graph;
+
+#[cfg(feature = "quickcheck")]
+mod quickchecks {
+    use fera_fun::vec;
+    use fera_graph::algs::{Kruskal, SpanningTrees, Trees};
+    use fera_graph::arbitrary::GnWithEdgeProp;
+    use fera_graph::prelude::*;
+    use fera_graph::sum_prop;
+
+    type Weight = DefaultEdgePropMut<StaticGraph, i32>;
+
+    // Returns the weights of all spanning trees in increasing order.
+    fn spanning_trees_weights_naive(g: &StaticGraph, w: &Weight) -> Vec<i32> {
+        let n = g.num_vertices();
+        let edges = vec(g.edges());
+        let mut weights = vec![];
+        for mask in 0..1u32 << edges.len() {
+            if mask.count_ones() as usize + 1 != n {
+                continue;
+            }
+            let tree = vec((0..edges.len())
+                .filter(|&i| mask & (1 << i) != 0)
+                .map(|i| edges[i]));
+            if g.spanning_subgraph(&tree).is_tree() {
+                weights.push(sum_prop(w, &tree));
+            }
+        }
+        weights.sort();
+        weights
+    }
+
+    fn msf_weight(g: &StaticGraph, w: &Weight) -> i32 {
+        sum_prop(w, g.kruskal_mst(w))
+    }
+
+    quickcheck! {
+        fn spanning_trees_by_weight(x: GnWithEdgeProp<StaticGraph, i8>) -> bool {
+            let GnWithEdgeProp(g, w) = x;
+            if g.num_vertices() == 0 || g.num_edges() > 12 {
+                return true;
+            }
+            let w = g.edge_prop_from_fn(|e| i32::from(w[e]));
+            let trees = vec(g.spanning_trees_by_weight(&w));
+            for (i, tree) in trees.iter().enumerate() {
+                assert!(tree.is_tree());
+                let mut edges = vec(tree.edges());
+                edges.sort();
+                for other in &trees[..i] {
+                    let mut other = vec(other.edges());
+                    other.sort();
+                    assert_ne!(edges, other);
+                }
+            }
+            let weights = vec(trees.iter().map(|tree| sum_prop(&w, tree.edges())));
+            spanning_trees_weights_naive(&g, &w) == weights
+        }
+
+        fn spanning_tree_sensitivity(x: GnWithEdgeProp<StaticGraph, i8>) -> bool {
+            let GnWithEdgeProp(g, w) = x;
+            if g.num_vertices() == 0 || g.num_vertices() > 20 {
+                return true;
+            }
+            let mut w: Weight = g.edge_prop_from_fn(|e| i32::from(w[e]));
+            let tree = vec(g.kruskal_mst(&w));
+            let range = g.spanning_tree_sensitivity(&w, &tree);
+            let in_tree = |e| tree.contains(&e);
+            for e in g.edges() {
+                let old = w[e];
+                let r = range[e];
+                // changes the weight of e and checks if tree is still minimum
+                let mut check = |x: i32| {
+                    w[e] = x;
+                    let min = msf_weight(&g, &w) == sum_prop::<_, _, i32, _>(&w, &tree);
+                    w[e] = old;
+                    min
+                };
+                if in_tree(e) {
+                    assert_eq!(None, r.lower);
+                    assert!(check(-1000));
+                    match r.upper {
+                        Some(upper) => assert!(check(upper) && !check(upper + 1)),
+                        None => assert!(check(1000)),
+                    }
+                } else {
+                    assert_eq!(None, r.upper);
+                    assert!(check(1000));
+                    match r.lower {
+                        Some(lower) => assert!(check(lower) && !check(lower - 1)),
+                        None => assert!(check(-1000)),
+                    }
+                }
+            }
+            true
+        }
+    }
+}
@@ -0,0 +1,71 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+#[cfg(feature = "quickcheck")]
+#[macro_use]
+extern crate quickcheck;
+extern crate fera_fun;
+extern crate fera_graph;
+
+#[cfg(feature = "quickcheck")]
+mod quickchecks {
+    use fera_fun::vec;
+    use fera_graph::algs::{Components, Cycles, Steiner};
+    use fera_graph::arbitrary::GnWithEdgeProp;
+    use fera_graph::prelude::*;
+    use fera_graph::sum_prop;
+
+    type Weight = DefaultEdgePropMut<StaticGraph, u32>;
+
+    // Returns true if each pair of terminals connected in g is connected in sub.
+    fn connects<G>(g: &StaticGraph, sub: &G, terminals: &[u32]) -> bool
+    where
+        G: Components + IncidenceGraph<Vertex = u32>,
+    {
+        let all = g.connected_components();
+        let some = sub.connected_components();
+        terminals.iter().all(|&u| {
+            terminals
+                .iter()
+                .all(|&v| all.is_disconnected(u, v) || some.is_connected(u, v))
+        })
+    }
+
+    // Computes the weight of a minimum Steiner tree (or forest) by trying all subsets of edges.
+    fn steiner_tree_naive(g: &StaticGraph, w: &Weight, terminals: &[u32]) -> u32 {
+        let edges = vec(g.edges());
+        (0..1u32 << edges.len())
+            .filter_map(|mask| {
+                let sub = vec((0..edges.len())
+                    .filter(|&i| mask & (1 << i) != 0)
+                    .map(|i| edges[i]));
+                if connects(g, &g.spanning_subgraph(&sub), terminals) {
+                    Some(sum_prop(w, &sub))
+                } else {
+                    None
+                }
+            })
+            .min()
+            .unwrap()
+    }
+
+    quickcheck! {
+        fn steiner_tree(x: GnWithEdgeProp<StaticGraph, u8>) -> bool {
+            let GnWithEdgeProp(g, w) = x;
+            if g.num_edges() > 12 {
+                return true;
+            }
+            let w: Weight = g.edge_prop_from_fn(|e| u32::from(w[e]));
+            let terminals = vec(g.vertices().filter(|v| v % 3 != 1));
+            let tree = g.steiner_tree(terminals.clone(), &w);
+            assert!(tree.is_acyclic());
+            assert!(connects(&g, &tree, &terminals));
+            for v in tree.vertices() {
+                assert!(tree.out_degree(v) != 1 || terminals.contains(&v));
+            }
+            let weight: u32 = sum_prop(&w, tree.edges());
+            weight <= 2 * steiner_tree_naive(&g, &w, &terminals)
+        }
+    }
+}
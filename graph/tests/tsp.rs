@@ -0,0 +1,98 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+#[cfg(feature = "quickcheck")]
+#[macro_use]
+extern crate quickcheck;
+extern crate fera_graph;
+
+#[cfg(feature = "quickcheck")]
+mod quickchecks {
+    use fera_graph::algs::Tsp;
+    use fera_graph::prelude::*;
+
+    type Weight = DefaultEdgePropMut<CompleteGraph, u32>;
+
+    // Builds a complete graph where the vertices are points in the plane with the manhattan
+    // distance, so the distances satisfy the triangle inequality.
+    fn graph(points: &[(u8, u8)]) -> (CompleteGraph, Weight) {
+        let g = CompleteGraph::new(points.len() as u32);
+        let w = g.edge_prop_from_fn(|e| {
+            let (x1, y1) = points[g.source(e) as usize];
+            let (x2, y2) = points[g.target(e) as usize];
+            let dist = |a: u8, b: u8| u32::from(a.max(b) - a.min(b));
+            dist(x1, x2) + dist(y1, y2)
+        });
+        (g, w)
+    }
+
+    fn cost(g: &CompleteGraph, w: &Weight, tour: &[u32]) -> u32 {
+        let n = tour.len();
+        if n == 1 {
+            return 0;
+        }
+        (0..n)
+            .map(|i| w[g.edge_by_ends(tour[i], tour[(i + 1) % n])])
+            .sum()
+    }
+
+    fn check(g: &CompleteGraph, w: &Weight, tour: &[u32], tour_cost: u32) {
+        let mut sorted = tour.to_vec();
+        sorted.sort();
+        assert_eq!(g.vertices().collect::<Vec<_>>(), sorted);
+        assert_eq!(cost(g, w, tour), tour_cost);
+    }
+
+    // Computes the cost of an optimal tour by trying all the tours that start at 0.
+    fn tsp_naive(g: &CompleteGraph, w: &Weight) -> u32 {
+        fn go(g: &CompleteGraph, w: &Weight, tour: &mut Vec<u32>, best: &mut u32) {
+            let n = g.num_vertices() as u32;
+            if tour.len() == n as usize {
+                *best = (*best).min(cost(g, w, tour));
+                return;
+            }
+            for v in 0..n {
+                if !tour.contains(&v) {
+                    tour.push(v);
+                    go(g, w, tour, best);
+                    tour.pop();
+                }
+            }
+        }
+        let mut best = u32::max_value();
+        go(g, w, &mut vec![0], &mut best);
+        best
+    }
+
+    quickcheck! {
+        fn tsp(points: Vec<(u8, u8)>) -> bool {
+            let mut points = points;
+            points.truncate(8);
+            if points.is_empty() {
+                return true;
+            }
+            let (g, w) = graph(&points);
+            let opt = tsp_naive(&g, &w);
+
+            let (tour, nn) = g.nearest_neighbor_tour(&w);
+            check(&g, &w, &tour, nn);
+            assert!(opt <= nn);
+
+            let (tour, christofides) = g.christofides_tour(&w);
+            check(&g, &w, &tour, christofides);
+            assert!(opt <= christofides && 2 * christofides <= 3 * opt);
+
+            for &(ref tour, c) in &[(tour.clone(), christofides), g.nearest_neighbor_tour(&w)] {
+                let (two_opt, two_opt_cost) = g.two_opt(&w, tour.clone());
+                check(&g, &w, &two_opt, two_opt_cost);
+                assert!(opt <= two_opt_cost && two_opt_cost <= c);
+
+                let (or_opt, or_opt_cost) = g.or_opt(&w, tour.clone());
+                check(&g, &w, &or_opt, or_opt_cost);
+                assert!(opt <= or_opt_cost && or_opt_cost <= c);
+            }
+            true
+        }
+    }
+}